@@ -0,0 +1,262 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Attribute, Data, DeriveInput, Fields, Ident, LitStr, Path, parse_quote, spanned::Spanned,
+};
+
+#[derive(Clone, Copy, Debug)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_str(value: &str) -> Option<Self> {
+        Some(match value {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+            _ => return None,
+        })
+    }
+
+    fn apply(self, variant: &str) -> String {
+        let words = split_words(variant);
+
+        match self {
+            Self::Lower => words.concat().to_lowercase(),
+            Self::Upper => words.concat().to_uppercase(),
+            Self::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+            Self::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(index, word)| {
+                    if index == 0 {
+                        word.to_lowercase()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            Self::Snake => words.join("_").to_lowercase(),
+            Self::ScreamingSnake => words.join("_").to_uppercase(),
+            Self::Kebab => words.join("-").to_lowercase(),
+            Self::ScreamingKebab => words.join("-").to_uppercase(),
+        }
+    }
+}
+
+fn split_words(value: &str) -> Vec<String> {
+    let mut words: Vec<String> = vec![];
+    let mut current = String::new();
+
+    for character in value.chars() {
+        if character == '_' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+        } else {
+            if character.is_uppercase() && !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            current.push(character);
+        }
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut characters = word.chars();
+    match characters.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(characters.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+#[derive(Debug, Default)]
+struct AttrValueAttrArgs {
+    rename_all: Option<RenameRule>,
+    rename: Option<String>,
+    krate: Option<Path>,
+    yew: Option<Path>,
+}
+
+fn parse_attr_value_attrs(attrs: &[Attribute]) -> Result<AttrValueAttrArgs, syn::Error> {
+    let mut args = AttrValueAttrArgs::default();
+
+    for attr in attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("attr_value"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                let value = meta.value().and_then(|value| value.parse::<LitStr>())?;
+
+                args.rename_all = Some(
+                    RenameRule::from_str(&value.value())
+                        .ok_or_else(|| syn::Error::new(value.span(), "unknown rename rule"))?,
+                );
+
+                Ok(())
+            } else if meta.path.is_ident("crate") {
+                let value = meta.value().and_then(|value| value.parse::<LitStr>())?;
+
+                args.krate = Some(value.parse::<Path>()?);
+
+                Ok(())
+            } else if meta.path.is_ident("yew") {
+                let value = meta.value().and_then(|value| value.parse::<LitStr>())?;
+
+                args.yew = Some(value.parse::<Path>()?);
+
+                Ok(())
+            } else if meta.path.is_ident("rename") {
+                let value = meta.value().and_then(|value| value.parse::<LitStr>())?;
+
+                args.rename = Some(value.value());
+
+                Ok(())
+            } else {
+                Err(meta.error("unknown property"))
+            }
+        })?;
+    }
+
+    Ok(args)
+}
+
+pub fn derive_attr_value_enum(derive_input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    let Data::Enum(data_enum) = &derive_input.data else {
+        return Err(syn::Error::new(derive_input.span(), "expected enum"));
+    };
+
+    let args = parse_attr_value_attrs(&derive_input.attrs)?;
+    if args.rename.is_some() {
+        return Err(syn::Error::new(
+            derive_input.span(),
+            "`rename` is only supported on variants",
+        ));
+    }
+
+    let mut variants: Vec<&Ident> = vec![];
+    let mut values: Vec<String> = vec![];
+
+    for variant in &data_enum.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new(variant.span(), "expected unit variant"));
+        }
+
+        let variant_args = parse_attr_value_attrs(&variant.attrs)?;
+        if variant_args.rename_all.is_some()
+            || variant_args.krate.is_some()
+            || variant_args.yew.is_some()
+        {
+            return Err(syn::Error::new(
+                variant.span(),
+                "`rename_all`, `crate` and `yew` are only supported on enums",
+            ));
+        }
+
+        let name = variant.ident.to_string();
+        let value = variant_args
+            .rename
+            .unwrap_or_else(|| match args.rename_all {
+                Some(rule) => rule.apply(&name),
+                None => name,
+            });
+
+        variants.push(&variant.ident);
+        values.push(value);
+    }
+
+    let ident = &derive_input.ident;
+    let krate = args
+        .krate
+        .unwrap_or_else(|| parse_quote!(::yew_struct_component));
+    let yew = args
+        .yew
+        .unwrap_or_else(|| parse_quote!(#krate::__private::yew));
+    let (impl_generics, type_generics, where_clause) = derive_input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #ident #type_generics #where_clause {
+            /// All variants, in declaration order.
+            pub const ALL: &'static [Self] = &[#(Self::#variants,)*];
+
+            /// Attribute values of all variants, in declaration order.
+            pub const VALUES: &'static [&'static str] = &[#(#values,)*];
+
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    #(Self::#variants => #values,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::std::fmt::Display for #ident #type_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl #impl_generics ::std::convert::AsRef<str> for #ident #type_generics #where_clause {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<#ident #type_generics> for #yew::virtual_dom::AttrValue #where_clause {
+            fn from(value: #ident #type_generics) -> #yew::virtual_dom::AttrValue {
+                #yew::virtual_dom::AttrValue::Static(value.as_str())
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<&#ident #type_generics> for #yew::virtual_dom::AttrValue #where_clause {
+            fn from(value: &#ident #type_generics) -> #yew::virtual_dom::AttrValue {
+                #yew::virtual_dom::AttrValue::Static(value.as_str())
+            }
+        }
+
+        impl #impl_generics #krate::IntoAttributeValue for #ident #type_generics #where_clause {
+            fn into_attribute_value(self) -> ::std::option::Option<#yew::virtual_dom::AttrValue> {
+                ::std::option::Option::Some(#yew::virtual_dom::AttrValue::Static(self.as_str()))
+            }
+        }
+
+        impl #impl_generics #krate::IntoAttributeValue for &#ident #type_generics #where_clause {
+            fn into_attribute_value(self) -> ::std::option::Option<#yew::virtual_dom::AttrValue> {
+                ::std::option::Option::Some(#yew::virtual_dom::AttrValue::Static(self.as_str()))
+            }
+        }
+
+        impl #impl_generics #yew::html::IntoPropValue<#yew::virtual_dom::AttrValue> for #ident #type_generics #where_clause {
+            fn into_prop_value(self) -> #yew::virtual_dom::AttrValue {
+                #yew::virtual_dom::AttrValue::Static(self.as_str())
+            }
+        }
+
+        impl #impl_generics #yew::html::IntoPropValue<::std::option::Option<#yew::virtual_dom::AttrValue>> for #ident #type_generics #where_clause {
+            fn into_prop_value(self) -> ::std::option::Option<#yew::virtual_dom::AttrValue> {
+                ::std::option::Option::Some(#yew::virtual_dom::AttrValue::Static(self.as_str()))
+            }
+        }
+    })
+}
@@ -0,0 +1,204 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Expr, Ident, LitStr, Path, Token,
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+use crate::diagnostics::Errors;
+
+enum Name {
+    Ident(Ident),
+    Lit(LitStr),
+}
+
+impl Name {
+    fn value(&self) -> String {
+        match self {
+            Name::Ident(ident) => ident.unraw().to_string().replace("_", "-"),
+            Name::Lit(lit) => lit.value(),
+        }
+    }
+
+    fn span(&self) -> Span {
+        match self {
+            Name::Ident(ident) => ident.span(),
+            Name::Lit(lit) => lit.span(),
+        }
+    }
+}
+
+impl Parse for Name {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            input.parse().map(Name::Lit)
+        } else if input.peek(Ident::peek_any) {
+            input.call(Ident::parse_any).map(Name::Ident)
+        } else {
+            Err(input.error("expected an attribute name"))
+        }
+    }
+}
+
+enum Entry {
+    /// `name`, rendered without a value.
+    Present(Name),
+    /// `name => value`
+    Value(Name, Expr),
+    /// `name => ?value`, skipped when the value is `None`.
+    Optional(Name, Expr),
+    /// `if condition => entry`
+    Conditional(Expr, Box<Entry>),
+}
+
+impl Entry {
+    fn name(&self) -> &Name {
+        match self {
+            Entry::Present(name) | Entry::Value(name, _) | Entry::Optional(name, _) => name,
+            Entry::Conditional(_, entry) => entry.name(),
+        }
+    }
+
+    fn to_tokens(&self, attributes: &Ident, krate: &TokenStream) -> TokenStream {
+        let insert = |name: &Name, value: TokenStream| {
+            let name = LitStr::new(&name.value(), name.span());
+
+            quote! {
+                #krate::Attributes::insert(&mut #attributes, #name, #value);
+            }
+        };
+
+        match self {
+            Entry::Present(name) => insert(name, quote! { true }),
+            Entry::Value(name, value) => insert(name, value.to_token_stream()),
+            Entry::Optional(name, value) => {
+                let local = Ident::new("value", Span::mixed_site());
+                let insert = insert(name, local.to_token_stream());
+
+                quote! {
+                    if let ::std::option::Option::Some(#local) = #value {
+                        #insert
+                    }
+                }
+            }
+            Entry::Conditional(condition, entry) => {
+                let entry = entry.to_tokens(attributes, krate);
+
+                quote! {
+                    if #condition {
+                        #entry
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Parse for Entry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![if]) {
+            input.parse::<Token![if]>()?;
+            let condition = input.call(Expr::parse_without_eager_brace)?;
+            input.parse::<Token![=>]>()?;
+
+            return Ok(Entry::Conditional(condition, Box::new(input.parse()?)));
+        }
+
+        let name: Name = input.parse()?;
+        if !input.peek(Token![=>]) {
+            return Ok(Entry::Present(name));
+        }
+        input.parse::<Token![=>]>()?;
+
+        if input.peek(Token![?]) {
+            input.parse::<Token![?]>()?;
+            Ok(Entry::Optional(name, input.parse()?))
+        } else {
+            Ok(Entry::Value(name, input.parse()?))
+        }
+    }
+}
+
+pub struct AttributesInput {
+    /// `crate = "path";`, for use through a facade crate.
+    krate: Option<Path>,
+    entries: Punctuated<Entry, Token![,]>,
+}
+
+impl Parse for AttributesInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // `=>` also starts with `=`, so it has to be excluded to allow an attribute named `crate`.
+        let krate =
+            if input.peek(Token![crate]) && input.peek2(Token![=]) && !input.peek2(Token![=>]) {
+                input.parse::<Token![crate]>()?;
+                input.parse::<Token![=]>()?;
+                let path = input.parse::<LitStr>()?.parse::<Path>()?;
+                input.parse::<Token![;]>()?;
+
+                Some(path)
+            } else {
+                None
+            };
+
+        Ok(AttributesInput {
+            krate,
+            entries: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+/// Check a name against the HTML syntax for attribute names.
+fn check_name(name: &Name, errors: &mut Errors) {
+    let value = name.value();
+
+    if value.is_empty() {
+        errors.push_spanned(name.span(), "attribute name must not be empty");
+    } else if let Some(character) = value.chars().find(|character| {
+        character.is_ascii_whitespace()
+            || character.is_control()
+            || matches!(character, '"' | '\'' | '>' | '/' | '=')
+    }) {
+        errors.push_spanned(
+            name.span(),
+            format!("invalid character {character:?} in attribute name `{value}`"),
+        );
+    }
+}
+
+pub fn attributes(input: AttributesInput) -> syn::Result<TokenStream> {
+    let mut errors = Errors::default();
+    for entry in &input.entries {
+        check_name(entry.name(), &mut errors);
+    }
+    if let Err(error) = errors.finish() {
+        // Wrap the errors in a block, so they are valid in expression position.
+        let error = error.to_compile_error();
+        return Ok(quote! { { #error } });
+    }
+
+    let krate = match &input.krate {
+        Some(krate) => krate.to_token_stream(),
+        None => quote! { ::yew_struct_component },
+    };
+    if input.entries.is_empty() {
+        return Ok(quote! {
+            <#krate::Attributes as ::std::default::Default>::default()
+        });
+    }
+
+    let attributes = Ident::new("attributes", Span::mixed_site());
+    let entries = input
+        .entries
+        .iter()
+        .map(|entry| entry.to_tokens(&attributes, &krate));
+
+    Ok(quote! {
+        {
+            let mut #attributes = <#krate::Attributes as ::std::default::Default>::default();
+            #(#entries)*
+            #attributes
+        }
+    })
+}
@@ -0,0 +1,79 @@
+use std::fmt::Display;
+
+use proc_macro2::Span;
+
+/// Collects errors so a derive can report every mistake at once.
+#[derive(Debug, Default)]
+pub struct Errors(Option<syn::Error>);
+
+impl Errors {
+    pub fn push(&mut self, error: syn::Error) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(error),
+            None => self.0 = Some(error),
+        }
+    }
+
+    pub fn push_spanned<T: Display>(&mut self, span: Span, message: T) {
+        self.push(syn::Error::new(span, message));
+    }
+
+    pub fn finish(self) -> Result<(), syn::Error> {
+        match self.0 {
+            Some(errors) => Err(errors),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Optimal string alignment distance, so transposed characters count as a single edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+/// Find the closest candidate to a misspelled name.
+///
+/// Allows one edit per four characters, so unrelated names of similar length are not suggested.
+pub fn suggest<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let threshold = (name.len() / 4).max(1);
+
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(name, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Format an optional suggestion as a message suffix.
+pub fn did_you_mean(suggestion: Option<&str>) -> String {
+    suggestion
+        .map(|suggestion| format!(", did you mean `{suggestion}`?"))
+        .unwrap_or_default()
+}
@@ -0,0 +1,1111 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::{
+    AttrStyle, Attribute, Data, DeriveInput, Field, Fields, Ident, LitBool, LitStr, Meta, Path,
+    Token, Type, ext::IdentExt, meta::ParseNestedMeta, parenthesized, parse_quote,
+    spanned::Spanned, token::Paren,
+};
+
+use crate::{
+    diagnostics::{Errors, did_you_mean, suggest},
+    listeners::LISTENERS,
+};
+
+const STRUCT_OPTIONS: &[&str] = &[
+    "tag",
+    "no_children",
+    "provided_attributes",
+    "sanitize",
+    "finish",
+    "crate",
+    "yew",
+];
+const FIELD_OPTIONS: &[&str] = &[
+    "dynamic_tag",
+    "children",
+    "text",
+    "inner_html",
+    "with",
+    "is_set",
+    "bind",
+    "auto_id",
+    "flatten",
+];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Target {
+    Struct,
+    Field,
+}
+
+#[derive(Debug, Default)]
+struct StructComponentAttrArgs {
+    tag: Option<LitStr>,
+    dynamic_tag: Option<LitBool>,
+    no_children: Option<LitBool>,
+    provided_attributes: Option<LitBool>,
+    sanitize: Option<Option<Path>>,
+    children: Option<LitBool>,
+    text: Option<LitBool>,
+    inner_html: Option<LitBool>,
+    with: Option<Path>,
+    is_set: Option<Path>,
+    bind: Option<Ident>,
+    auto_id: Option<LitBool>,
+    flatten: Option<LitBool>,
+    finish: Option<Path>,
+    krate: Option<Path>,
+    yew: Option<Path>,
+}
+
+fn is_set(flag: &Option<LitBool>) -> bool {
+    flag.as_ref().is_some_and(LitBool::value)
+}
+
+fn flag_span(flag: &Option<LitBool>) -> Option<Span> {
+    flag.as_ref().filter(|flag| flag.value).map(LitBool::span)
+}
+
+fn parse_flag(meta: &ParseNestedMeta) -> Result<LitBool, syn::Error> {
+    let value = if meta.input.peek(Token![=]) {
+        meta.value()
+            .and_then(|value| value.parse::<LitBool>())?
+            .value()
+    } else {
+        true
+    };
+
+    // Point diagnostics at the option name instead of the value.
+    Ok(LitBool::new(value, meta.path.span()))
+}
+
+fn skip_value(meta: &ParseNestedMeta) -> Result<(), syn::Error> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(Paren) {
+        let content;
+        parenthesized!(content in meta.input);
+        content.parse::<TokenStream>()?;
+    }
+
+    Ok(())
+}
+
+fn set_option<T>(option: &mut Option<T>, value: T, meta: &ParseNestedMeta, errors: &mut Errors) {
+    if option.is_some() {
+        errors.push(meta.error(format!(
+            "duplicate option `{}`",
+            meta.path.to_token_stream()
+        )));
+    } else {
+        *option = Some(value);
+    }
+}
+
+fn parse_struct_component_attrs(
+    attrs: &[Attribute],
+    target: Target,
+    errors: &mut Errors,
+) -> StructComponentAttrArgs {
+    let mut args = StructComponentAttrArgs::default();
+
+    for attr in attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("struct_component"))
+    {
+        if !matches!(attr.style, AttrStyle::Outer) {
+            errors.push_spanned(attr.span(), "expected an outer attribute");
+            continue;
+        }
+
+        let Meta::List(list) = &attr.meta else {
+            errors.push_spanned(attr.meta.span(), "expected `#[struct_component(...)]`");
+            continue;
+        };
+
+        let result = list.parse_nested_meta(|meta| {
+            let name = meta.path.to_token_stream().to_string();
+
+            let (options, other_options, other_target) = match target {
+                Target::Struct => (STRUCT_OPTIONS, FIELD_OPTIONS, "fields"),
+                Target::Field => (FIELD_OPTIONS, STRUCT_OPTIONS, "the struct"),
+            };
+
+            if !options.contains(&name.as_str()) {
+                if other_options.contains(&name.as_str()) {
+                    errors
+                        .push(meta.error(format!("`{name}` is only supported on {other_target}")));
+                } else {
+                    errors.push(meta.error(format!(
+                        "unknown option `{name}`{}",
+                        did_you_mean(suggest(&name, options))
+                    )));
+                }
+
+                return skip_value(&meta);
+            }
+
+            match name.as_str() {
+                "tag" => {
+                    let value = meta.value().and_then(|value| value.parse::<LitStr>())?;
+                    set_option(&mut args.tag, value, &meta, errors);
+                }
+                "dynamic_tag" => {
+                    let value = parse_flag(&meta)?;
+                    set_option(&mut args.dynamic_tag, value, &meta, errors);
+                }
+                "no_children" => {
+                    let value = parse_flag(&meta)?;
+                    set_option(&mut args.no_children, value, &meta, errors);
+                }
+                "provided_attributes" => {
+                    let value = parse_flag(&meta)?;
+                    set_option(&mut args.provided_attributes, value, &meta, errors);
+                }
+                "sanitize" => {
+                    let value = if meta.input.peek(Token![=]) {
+                        Some(meta.value().and_then(|value| value.parse::<Path>())?)
+                    } else {
+                        None
+                    };
+                    set_option(&mut args.sanitize, value, &meta, errors);
+                }
+                "children" => {
+                    let value = parse_flag(&meta)?;
+                    set_option(&mut args.children, value, &meta, errors);
+                }
+                "text" => {
+                    let value = parse_flag(&meta)?;
+                    set_option(&mut args.text, value, &meta, errors);
+                }
+                "inner_html" => {
+                    let value = parse_flag(&meta)?;
+                    set_option(&mut args.inner_html, value, &meta, errors);
+                }
+                "with" => {
+                    let value = meta.value().and_then(|value| value.parse::<Path>())?;
+                    set_option(&mut args.with, value, &meta, errors);
+                }
+                "is_set" => {
+                    let value = meta.value().and_then(|value| value.parse::<Path>())?;
+                    set_option(&mut args.is_set, value, &meta, errors);
+                }
+                "bind" => {
+                    let value = meta.value().and_then(|value| value.parse::<Ident>())?;
+                    set_option(&mut args.bind, value, &meta, errors);
+                }
+                "auto_id" => {
+                    let value = parse_flag(&meta)?;
+                    set_option(&mut args.auto_id, value, &meta, errors);
+                }
+                "flatten" => {
+                    let value = parse_flag(&meta)?;
+                    set_option(&mut args.flatten, value, &meta, errors);
+                }
+                "finish" => {
+                    let value = meta.value().and_then(|value| value.parse::<Path>())?;
+                    set_option(&mut args.finish, value, &meta, errors);
+                }
+                "crate" => {
+                    let value = meta.value().and_then(|value| value.parse::<LitStr>())?;
+                    set_option(&mut args.krate, value.parse::<Path>()?, &meta, errors);
+                }
+                "yew" => {
+                    let value = meta.value().and_then(|value| value.parse::<LitStr>())?;
+                    set_option(&mut args.yew, value.parse::<Path>()?, &meta, errors);
+                }
+                _ => unreachable!("option should be known"),
+            }
+
+            Ok(())
+        });
+
+        if let Err(error) = result {
+            errors.push(error);
+        }
+    }
+
+    args
+}
+
+fn check_field_conflicts(args: &StructComponentAttrArgs, errors: &mut Errors) {
+    let options = [
+        ("dynamic_tag", flag_span(&args.dynamic_tag)),
+        ("children", flag_span(&args.children)),
+        ("text", flag_span(&args.text)),
+        ("inner_html", flag_span(&args.inner_html)),
+        ("with", args.with.as_ref().map(Spanned::span)),
+        ("bind", args.bind.as_ref().map(Spanned::span)),
+        ("auto_id", flag_span(&args.auto_id)),
+        ("flatten", flag_span(&args.flatten)),
+    ]
+    .into_iter()
+    .filter_map(|(name, span)| span.map(|span| (name, span)))
+    .collect::<Vec<_>>();
+
+    if let Some(((first, _), rest)) = options.split_first() {
+        for (name, span) in rest {
+            errors.push_spanned(*span, format!("`{name}` conflicts with `{first}`"));
+        }
+    }
+}
+
+fn last_segment_is(ty: &Type, name: &str) -> bool {
+    matches!(ty, Type::Path(path) if path
+        .path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == name))
+}
+
+/// Values passed from the owned and borrowed render paths to the shared render function.
+#[derive(Default)]
+struct Parts {
+    types: Vec<TokenStream>,
+    owned: Vec<TokenStream>,
+    borrowed: Vec<TokenStream>,
+    clone_types: Vec<Type>,
+}
+
+impl Parts {
+    /// Add a value, returning the matching parameter of the render function.
+    fn push(&mut self, ty: TokenStream, owned: TokenStream, borrowed: TokenStream) -> Ident {
+        let parameter = Ident::new(&format!("part{}", self.types.len()), Span::mixed_site());
+
+        self.types.push(ty);
+        self.owned.push(owned);
+        self.borrowed.push(borrowed);
+
+        parameter
+    }
+
+    /// Add a value converted from the field, which is cloned on the borrowed path.
+    fn converted(
+        &mut self,
+        ident: &Ident,
+        field: &Field,
+        ty: TokenStream,
+        convert: impl Fn(TokenStream) -> TokenStream,
+    ) -> Ident {
+        self.clone_types.push(field.ty.clone());
+
+        self.push(
+            ty,
+            convert(quote! { self.#ident }),
+            convert(quote! { ::std::clone::Clone::clone(&self.#ident) }),
+        )
+    }
+
+    /// Add a field which is cheap to clone, like callbacks and node refs.
+    fn handle(&mut self, ident: &Ident, field: &Field) -> Ident {
+        let ty = &field.ty;
+
+        self.converted(ident, field, quote! { #ty }, |value| value)
+    }
+}
+
+/// Whether a typed attribute field is set, used to let provided defaults fill unset fields.
+///
+/// Fields converted `with` a function are set unless an `is_set` function says otherwise.
+fn is_set_field(ident: &Ident, ty: &Type, is_set: Option<&Path>) -> TokenStream {
+    if let Some(is_set) = is_set {
+        quote! { #is_set(&self.#ident) }
+    } else if last_segment_is(ty, "bool") {
+        quote! { self.#ident }
+    } else if last_segment_is(ty, "Option") || last_segment_is(ty, "Style") {
+        quote! { ::std::option::Option::is_some(&self.#ident) }
+    } else {
+        quote! { true }
+    }
+}
+
+fn attribute_name(ident: &Ident) -> String {
+    let name = ident.to_string().replace("_", "-");
+
+    match name.strip_prefix("r#") {
+        Some(name) => name.to_string(),
+        None => name,
+    }
+}
+
+pub fn derive_struct_component(derive_input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    let mut errors = Errors::default();
+
+    let args = parse_struct_component_attrs(&derive_input.attrs, Target::Struct, &mut errors);
+
+    let fields: Vec<&Field> = match &derive_input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields) => fields.named.iter().collect(),
+            Fields::Unit => vec![],
+            Fields::Unnamed(fields) => {
+                errors.push_spanned(fields.span(), "expected named fields");
+                return errors.finish().map(|_| TokenStream::new());
+            }
+        },
+        Data::Enum(data_enum) => {
+            errors.push_spanned(data_enum.enum_token.span(), "expected struct");
+            return errors.finish().map(|_| TokenStream::new());
+        }
+        Data::Union(data_union) => {
+            errors.push_spanned(data_union.union_token.span(), "expected struct");
+            return errors.finish().map(|_| TokenStream::new());
+        }
+    };
+
+    let ident = derive_input.ident.clone();
+    let krate = args
+        .krate
+        .clone()
+        .unwrap_or_else(|| parse_quote!(::yew_struct_component));
+    let yew = args
+        .yew
+        .clone()
+        .unwrap_or_else(|| parse_quote!(#krate::__private::yew));
+
+    // Mixed-site hygiene keeps the generated local from clashing with user code.
+    let tag = Ident::new("tag", Span::mixed_site());
+
+    let attribute = |value: TokenStream| {
+        quote! {
+            #yew::virtual_dom::AttributeOrProperty::Attribute(#value)
+        }
+    };
+
+    let meta = |field: &Ident, name: &str, kind: &str, optional: bool| {
+        let field = field.unraw().to_string();
+        let kind = Ident::new(kind, Span::call_site());
+
+        quote! {
+            #krate::AttributeMeta {
+                field: #field,
+                name: #name,
+                kind: #krate::AttributeKind::#kind,
+                optional: #optional,
+            }
+        }
+    };
+
+    // Fields are converted to parts, so the owned and borrowed paths can share the generated body.
+    let mut parts = Parts::default();
+
+    let mut metas: Vec<TokenStream> = vec![];
+    let mut attributes: Vec<(String, TokenStream)> = vec![];
+    let mut attributes_set: Vec<TokenStream> = vec![];
+    let mut attribute_checked: Option<TokenStream> = None;
+    let mut attribute_value: Option<TokenStream> = None;
+    let mut listeners: Vec<(Ident, TokenStream)> = vec![];
+    let mut attributes_map: Option<TokenStream> = None;
+    let mut flattened: Vec<TokenStream> = vec![];
+    let mut tag_name: Option<TokenStream> = None;
+    let mut node_ref: Option<TokenStream> = None;
+    let mut contents: Vec<TokenStream> = vec![];
+    let mut auto_id: Option<Ident> = None;
+
+    let field_args = fields
+        .iter()
+        .map(|field| {
+            let field_args = parse_struct_component_attrs(&field.attrs, Target::Field, &mut errors);
+            check_field_conflicts(&field_args, &mut errors);
+            field_args
+        })
+        .collect::<Vec<_>>();
+
+    // Callbacks targeted by `bind` are attached as a listener of the bound field.
+    let mut bound: Vec<(Ident, &str)> = vec![];
+    for (field, field_args) in fields.iter().zip(&field_args) {
+        let (Some(ident), Some(target)) = (&field.ident, &field_args.bind) else {
+            continue;
+        };
+
+        let (listener, helper) = if ident == "value" {
+            ("oninput", quote! { bind_value })
+        } else if ident == "checked" {
+            ("onchange", quote! { bind_checked })
+        } else {
+            errors.push_spanned(
+                target.span(),
+                "`bind` is only supported on `value` and `checked` fields",
+            );
+            continue;
+        };
+
+        let Some(target_field) = fields
+            .iter()
+            .find(|field| field.ident.as_ref() == Some(target))
+        else {
+            let names = fields
+                .iter()
+                .filter_map(|field| field.ident.as_ref().map(ToString::to_string))
+                .collect::<Vec<_>>();
+            let names = names.iter().map(String::as_str).collect::<Vec<_>>();
+
+            errors.push_spanned(
+                target.span(),
+                format!(
+                    "unknown field `{target}`{}",
+                    did_you_mean(suggest(&target.to_string(), &names))
+                ),
+            );
+            continue;
+        };
+
+        let target_part = parts.handle(target, target_field);
+        listeners.push((
+            Ident::new(listener, target.span()),
+            quote! {
+                #krate::__private::#helper(#target_part)
+            },
+        ));
+        bound.push((target.clone(), listener));
+    }
+
+    for (field, field_args) in fields.into_iter().zip(field_args) {
+        let Some(ident) = &field.ident else {
+            continue;
+        };
+
+        if let Some(is_set) = &field_args.is_set
+            && field_args.with.is_none()
+        {
+            errors.push_spanned(is_set.span(), "`is_set` requires `with`");
+        }
+
+        if let Some(span) = flag_span(&field_args.auto_id) {
+            if !last_segment_is(&field.ty, "Option") {
+                errors.push_spanned(span, "`auto_id` requires an `Option` field");
+            } else if auto_id.is_some() {
+                errors.push_spanned(span, "duplicate `auto_id` field");
+            } else {
+                auto_id = Some(ident.clone());
+            }
+        }
+
+        if let Some((_, listener)) = bound.iter().find(|(target, _)| target == ident) {
+            metas.push(meta(ident, listener, "Listener", true));
+
+            continue;
+        }
+
+        if let Some(span) = flag_span(&field_args.dynamic_tag) {
+            if args.tag.is_some() {
+                errors.push_spanned(
+                    span,
+                    "`dynamic_tag` conflicts with `#[struct_component(tag = \"...\")]`",
+                );
+            } else if tag_name.is_some() {
+                errors.push_spanned(span, "duplicate `dynamic_tag` field");
+            } else {
+                let ty = &field.ty;
+                let part = parts.push(
+                    quote! { ::std::string::String },
+                    quote! { <#ty as ::std::string::ToString>::to_string(&self.#ident) },
+                    quote! { <#ty as ::std::string::ToString>::to_string(&self.#ident) },
+                );
+
+                tag_name = Some(quote! { #part });
+            }
+
+            metas.push(meta(ident, "", "DynamicTag", false));
+
+            continue;
+        }
+
+        if is_set(&field_args.children)
+            || is_set(&field_args.text)
+            || is_set(&field_args.inner_html)
+        {
+            metas.push(meta(
+                ident,
+                "",
+                "Content",
+                last_segment_is(&field.ty, "Option"),
+            ));
+        }
+
+        if is_set(&field_args.children) || is_set(&field_args.text) {
+            // `Html` itself doesn't implement `ToHtml`.
+            let ty = &field.ty;
+            let part = if last_segment_is(ty, "Html") || last_segment_is(ty, "VNode") {
+                parts.handle(ident, field)
+            } else {
+                parts.push(
+                    quote! { #yew::prelude::Html },
+                    quote! { <#ty as #yew::html::ToHtml>::into_html(self.#ident) },
+                    quote! { <#ty as #yew::html::ToHtml>::to_html(&self.#ident) },
+                )
+            };
+
+            contents.push(quote! {
+                #tag.add_child(#part);
+            });
+
+            continue;
+        }
+
+        if is_set(&field_args.inner_html) {
+            let optional = last_segment_is(&field.ty, "Option");
+            let part = parts.converted(
+                ident,
+                field,
+                quote! { ::std::option::Option<#yew::virtual_dom::AttrValue> },
+                |value| {
+                    if optional {
+                        quote! {
+                            ::std::option::Option::map(
+                                #value,
+                                <#yew::virtual_dom::AttrValue as ::std::convert::From<_>>::from,
+                            )
+                        }
+                    } else {
+                        quote! {
+                            ::std::option::Option::Some(
+                                <#yew::virtual_dom::AttrValue as ::std::convert::From<_>>::from(#value),
+                            )
+                        }
+                    }
+                },
+            );
+
+            contents.push(quote! {
+                if let ::std::option::Option::Some(value) = #part {
+                    #tag.add_child(#yew::virtual_dom::VNode::from_html_unchecked(value));
+                }
+            });
+
+            continue;
+        }
+
+        if let Some(with) = &field_args.with {
+            metas.push(meta(ident, &attribute_name(ident), "Attribute", true));
+            attributes_set.push(is_set_field(ident, &field.ty, field_args.is_set.as_ref()));
+
+            let attribute = attribute(quote! { value });
+            let part = parts.converted(
+                ident,
+                field,
+                quote! { ::std::option::Option<#yew::virtual_dom::AttributeOrProperty> },
+                |value| {
+                    quote! {
+                        ::std::option::Option::map(#with(#value), |value| #attribute)
+                    }
+                },
+            );
+
+            attributes.push((attribute_name(ident), quote! { #part }));
+
+            continue;
+        }
+
+        if is_set(&field_args.flatten) {
+            metas.push(meta(ident, "", "Attributes", true));
+
+            let part = parts.converted(ident, field, quote! { #krate::Attributes }, |value| {
+                quote! {
+                    <#krate::Attributes as ::std::convert::From<_>>::from(#value)
+                }
+            });
+
+            flattened.push(quote! {
+                ::std::iter::Iterator::filter_map(
+                    ::std::iter::IntoIterator::into_iter(#part),
+                    |(key, value)| ::std::option::Option::map(value, |value| (
+                        key,
+                        #yew::virtual_dom::AttributeOrProperty::Attribute(value),
+                    )),
+                )
+            });
+
+            continue;
+        }
+
+        if ident == "attributes" {
+            metas.push(meta(ident, "", "Attributes", true));
+
+            let part = parts.handle(ident, field);
+
+            attributes_map = Some(quote! {
+                ::std::iter::Iterator::filter_map(
+                    ::std::iter::IntoIterator::into_iter(&#part),
+                    |(key, value)| ::std::option::Option::map(
+                        ::std::option::Option::as_ref(value),
+                        |value| (
+                            <#yew::virtual_dom::AttrValue as ::std::convert::From<_>>::from(
+                                ::std::clone::Clone::clone(key),
+                            ),
+                            #yew::virtual_dom::AttributeOrProperty::Attribute(
+                                <#yew::virtual_dom::AttrValue as ::std::convert::From<_>>::from(
+                                    ::std::clone::Clone::clone(value),
+                                ),
+                            ),
+                        ),
+                    ),
+                )
+            });
+
+            continue;
+        }
+
+        if ident == "node_ref" {
+            metas.push(meta(ident, "", "NodeRef", true));
+
+            let part = parts.handle(ident, field);
+
+            node_ref = Some(quote! {
+                #tag.node_ref = #part;
+            });
+
+            continue;
+        }
+
+        let name = ident.to_string();
+        if name.starts_with("on") && last_segment_is(&field.ty, "Callback") {
+            if !LISTENERS.contains(&name.as_str()) {
+                errors.push_spanned(
+                    ident.span(),
+                    format!(
+                        "unknown listener `{name}`{}",
+                        did_you_mean(suggest(&name, LISTENERS))
+                    ),
+                );
+            } else {
+                metas.push(meta(ident, &name, "Listener", false));
+                let part = parts.handle(ident, field);
+
+                listeners.push((ident.clone(), quote! { #part }));
+            }
+
+            continue;
+        }
+
+        if ident == "checked" {
+            let ty = &field.ty;
+            let part = parts.push(
+                quote! { #ty },
+                quote! { self.#ident },
+                quote! { self.#ident },
+            );
+
+            attribute_checked = Some(quote! {
+                #tag.set_checked(#part);
+            });
+        }
+
+        if ident == "value" {
+            // The value is also rendered as attribute, so it is cloned on the owned path too.
+            let value = quote! {
+                #yew::html::IntoPropValue::<
+                    ::std::option::Option<#yew::virtual_dom::AttrValue>,
+                >::into_prop_value(::std::clone::Clone::clone(&self.#ident))
+            };
+            parts.clone_types.push(field.ty.clone());
+            let part = parts.push(
+                quote! { ::std::option::Option<#yew::virtual_dom::AttrValue> },
+                value.clone(),
+                value,
+            );
+
+            attribute_value = Some(quote! {
+                #tag.set_value(#part);
+            });
+        }
+
+        match &field.ty {
+            Type::Path(path) => {
+                let name = attribute_name(ident);
+
+                let last = path.path.segments.last();
+
+                metas.push(meta(
+                    ident,
+                    &name,
+                    if ident == "checked" || ident == "value" {
+                        "Property"
+                    } else {
+                        "Attribute"
+                    },
+                    last.is_some_and(|segment| {
+                        ["bool", "Option", "Style"]
+                            .iter()
+                            .any(|name| segment.ident == name)
+                    }),
+                ));
+
+                attributes_set.push(is_set_field(ident, &field.ty, None));
+                let ty = quote! { ::std::option::Option<#yew::virtual_dom::AttributeOrProperty> };
+                let part = if last.is_some_and(|segment| segment.ident == "bool") {
+                    let attribute = attribute(quote! { #yew::virtual_dom::AttrValue::Static("") });
+                    let value = quote! {
+                        ::std::primitive::bool::then_some(self.#ident, #attribute)
+                    };
+
+                    parts.push(ty, value.clone(), value)
+                } else if last.is_some_and(|segment| segment.ident == "AttrValue") {
+                    parts.converted(ident, field, ty, |value| {
+                        let attribute = attribute(value);
+
+                        quote! {
+                            ::std::option::Option::Some(#attribute)
+                        }
+                    })
+                } else if last.is_some_and(|segment| segment.ident == "Option") {
+                    let attribute = attribute(
+                        quote! { <#yew::virtual_dom::AttrValue as ::std::convert::From<_>>::from(value) },
+                    );
+
+                    parts.converted(ident, field, ty, |value| {
+                        quote! {
+                            ::std::option::Option::map(#value, |value| #attribute)
+                        }
+                    })
+                } else if last.is_some_and(|segment| segment.ident == "Style") {
+                    // Styles are borrowed, so neither path clones them.
+                    let attribute = attribute(
+                        quote! { <#yew::virtual_dom::AttrValue as ::std::convert::From<_>>::from(value) },
+                    );
+                    let value = quote! {
+                        ::std::option::Option::map(
+                            ::std::option::Option::as_ref(&self.#ident),
+                            |value| #attribute,
+                        )
+                    };
+
+                    parts.push(ty, value.clone(), value)
+                } else {
+                    parts.converted(ident, field, ty, |value| {
+                        let attribute = attribute(
+                            quote! { <#yew::virtual_dom::AttrValue as ::std::convert::From<_>>::from(#value) },
+                        );
+
+                        quote! {
+                            ::std::option::Option::Some(#attribute)
+                        }
+                    })
+                };
+
+                // Inputs and text areas render these properties themselves, so the attribute would be duplicated.
+                let properties = match ident.to_string().as_str() {
+                    "value" => &["input", "textarea"][..],
+                    "checked" => &["input"][..],
+                    _ => &[][..],
+                };
+                attributes.push((
+                    name,
+                    if properties.is_empty() {
+                        quote! { #part }
+                    } else {
+                        quote! {
+                            ::std::option::Option::filter(#part, |_| {
+                                !::std::iter::Iterator::any(
+                                    &mut <[&::std::primitive::str]>::iter(&[#(#properties,)*]),
+                                    |property| #tag.tag().eq_ignore_ascii_case(property),
+                                )
+                            })
+                        }
+                    },
+                ));
+            }
+            _ => {
+                errors.push_spanned(field.ty.span(), "expected type path");
+            }
+        }
+    }
+
+    let static_tag = match (&tag_name, &args.tag) {
+        (None, Some(tag)) => quote! { ::std::option::Option::Some(#tag) },
+        _ => quote! { ::std::option::Option::None },
+    };
+
+    if let Some(span) = flag_span(&args.provided_attributes)
+        && attributes_map.is_none()
+    {
+        errors.push_spanned(span, "`provided_attributes` requires an `attributes` field");
+    }
+
+    let tag_name = tag_name.or_else(|| args.tag.as_ref().map(ToTokens::to_token_stream));
+    if tag_name.is_none() {
+        errors.push_spanned(
+            ident.span(),
+            "`#[struct_component(tag = \"...\")]` or a `#[struct_component(dynamic_tag)]` field is required",
+        );
+    }
+
+    errors.finish()?;
+    let tag_name = tag_name.expect("Tag should be set.");
+
+    // Only fields which are cloned on the borrowed path need `Clone`.
+    let clone_bounds = parts.clone_types.iter().map(|ty| {
+        // The higher-ranked bound keeps the impl from failing for fields without `Clone`.
+        quote! {
+            for<'a> #ty: ::std::clone::Clone
+        }
+    });
+    let clone_bounds = quote! { #(#clone_bounds,)* };
+
+    let no_children = is_set(&args.no_children) || !contents.is_empty();
+
+    let (children_parameter, children_argument) = if no_children {
+        (quote! {}, quote! {})
+    } else {
+        (
+            quote! { , children: #yew::prelude::Html },
+            quote! { children },
+        )
+    };
+
+    let children = (!no_children).then(|| {
+        quote! {
+            #tag.add_child(children);
+        }
+    });
+
+    let (attribute_names, mut attribute_values): (Vec<_>, Vec<_>) = attributes.into_iter().unzip();
+
+    // Flattened fields come before the `attributes` field, so the latter takes precedence.
+    let mut attributes_map =
+        flattened
+            .into_iter()
+            .chain(attributes_map)
+            .reduce(|chain, attributes_map| {
+                quote! {
+                    ::std::iter::Iterator::chain(#chain, #attributes_map)
+                }
+            });
+
+    // Sanitized values are filtered for the rendered tag, which is only known at runtime for dynamic tags.
+    if let Some(policy) = &args.sanitize {
+        let policy = match policy {
+            Some(policy) => quote! { #policy },
+            None => quote! { #krate::Policy::DEFAULT },
+        };
+
+        attribute_values = attribute_names
+            .iter()
+            .zip(attribute_values)
+            .map(|(name, value)| {
+                quote! {
+                    #krate::__private::sanitize(&#policy, #tag.tag(), #name, #value)
+                }
+            })
+            .collect();
+        attributes_map = attributes_map.map(|attributes_map| {
+            quote! {
+                ::std::iter::Iterator::filter_map(
+                    #attributes_map,
+                    |(key, value)| ::std::option::Option::map(
+                        #krate::__private::sanitize(
+                            &#policy,
+                            #tag.tag(),
+                            &key,
+                            ::std::option::Option::Some(value),
+                        ),
+                        |value| (key, value),
+                    ),
+                )
+            }
+        });
+    }
+    let attribute_count = attribute_names.len();
+
+    let attribute_keys = quote! {
+        <[&'static ::std::primitive::str; #attribute_count] as ::std::convert::From<_>>::from([
+            #(#attribute_names,)*
+        ])
+    };
+    let attribute_values = quote! {
+        <[::std::option::Option<#yew::virtual_dom::AttributeOrProperty>; #attribute_count] as ::std::convert::From<_>>::from([
+            #(#attribute_values,)*
+        ])
+    };
+
+    // Statically known keys diff much faster, so only fall back to an index map for additional attributes.
+    let set_attributes = match attributes_map {
+        Some(attributes_map) => quote! {
+            #tag.set_attributes(#yew::virtual_dom::Attributes::IndexMap(
+                ::std::rc::Rc::new(::std::iter::Iterator::collect(
+                    ::std::iter::Iterator::chain(
+                        ::std::iter::Iterator::filter_map(
+                            ::std::iter::Iterator::zip(
+                                ::std::iter::IntoIterator::into_iter(#attribute_keys),
+                                ::std::iter::IntoIterator::into_iter(#attribute_values),
+                            ),
+                            |(key, value)| ::std::option::Option::map(value, |value| (
+                                #yew::virtual_dom::AttrValue::Static(key),
+                                value,
+                            )),
+                        ),
+                        #attributes_map,
+                    ),
+                )),
+            ));
+        },
+        None => quote! {
+            #tag.set_attributes(#yew::virtual_dom::Attributes::Dynamic {
+                keys: &[#(#attribute_names,)*],
+                values: ::std::boxed::Box::new(#attribute_values),
+            });
+        },
+    };
+
+    let listener_count = listeners.len();
+    let listeners = listeners.iter().map(|(listener, value)| {
+        quote_spanned! {listener.span()=>
+            #yew::html::#listener::Wrapper::__macro_new(#value)
+        }
+    });
+
+    let finish = args.finish.map(|finish| {
+        quote! {
+            #finish(&mut #tag);
+        }
+    });
+
+    let conversions = no_children.then(|| {
+        quote! {
+            impl ::std::convert::From<#ident> for #yew::prelude::Html {
+                fn from(value: #ident) -> #yew::prelude::Html {
+                    value.render()
+                }
+            }
+
+            impl #yew::html::ToHtml for #ident
+            where
+                #clone_bounds
+            {
+                fn to_html(&self) -> #yew::prelude::Html {
+                    self.render_ref()
+                }
+
+                fn into_html(self) -> #yew::prelude::Html {
+                    self.render()
+                }
+            }
+        }
+    });
+
+    let Parts {
+        types,
+        owned,
+        borrowed,
+        ..
+    } = parts;
+    let parameters = (0..types.len())
+        .map(|index| Ident::new(&format!("part{index}"), Span::mixed_site()))
+        .collect::<Vec<_>>();
+    let children_input = (!no_children).then(|| quote! { children: #yew::prelude::Html });
+
+    let component = ident.to_string();
+    let span = Ident::new("_span", Span::mixed_site());
+
+    let body = quote! {
+        let #span = #krate::__private::enter_render(#component);
+
+        let mut #tag = #yew::virtual_dom::VTag::new(#tag_name);
+        #node_ref
+
+        #attribute_checked
+        #attribute_value
+        #set_attributes
+
+        #tag.set_listeners(::std::boxed::Box::new([
+            #(#listeners,)*
+        ]));
+
+        #children
+        #(#contents)*
+
+        #finish
+
+        #krate::__private::rendered(&#tag, #listener_count);
+
+        #tag
+    };
+
+    let provided_attributes = is_set(&args.provided_attributes).then(|| {
+        quote! {
+            impl #krate::ProvidedAttributes for #ident {
+                fn with_provided_attributes(mut self, mut defaults: #krate::Attributes) -> Self {
+                    // Typed fields which are unset don't render, so their defaults still apply.
+                    let set = [#((#attribute_names, #attributes_set),)*];
+                    defaults.retain(|key, _| {
+                        !::std::iter::Iterator::any(
+                            &mut ::std::iter::IntoIterator::into_iter(set),
+                            |(name, is_set): (&::std::primitive::str, ::std::primitive::bool)| is_set && key.as_str() == name,
+                        )
+                    });
+
+                    self.attributes = self.attributes.with_defaults(defaults);
+                    self
+                }
+            }
+        }
+    });
+
+    let auto_id = auto_id.map(|field| {
+        quote! {
+            impl #krate::AutoId for #ident {
+                fn with_auto_id(mut self, id: #yew::virtual_dom::AttrValue) -> Self {
+                    if ::std::option::Option::is_none(&self.#field) {
+                        self.#field = ::std::option::Option::Some(::std::convert::From::from(
+                            ::std::string::ToString::to_string(&id),
+                        ));
+                    }
+
+                    self
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #ident {
+            #[doc(hidden)]
+            #[allow(clippy::too_many_arguments)]
+            fn __struct_component_vtag(
+                #(#parameters: #types,)*
+                #children_input
+            ) -> #yew::virtual_dom::VTag {
+                #body
+            }
+
+            pub fn into_vtag(self #children_parameter) -> #yew::virtual_dom::VTag {
+                Self::__struct_component_vtag(#(#owned,)* #children_argument)
+            }
+
+            pub fn to_vtag(&self #children_parameter) -> #yew::virtual_dom::VTag
+            where
+                #clone_bounds
+            {
+                Self::__struct_component_vtag(#(#borrowed,)* #children_argument)
+            }
+
+            pub fn render(self #children_parameter) -> #yew::prelude::Html {
+                let #tag = self.into_vtag(#children_argument);
+                #krate::__private::check(&#tag);
+                ::std::convert::Into::into(#tag)
+            }
+
+            pub fn render_ref(&self #children_parameter) -> #yew::prelude::Html
+            where
+                #clone_bounds
+            {
+                let #tag = self.to_vtag(#children_argument);
+                #krate::__private::check(&#tag);
+                ::std::convert::Into::into(#tag)
+            }
+        }
+
+        impl #krate::StructComponentMeta for #ident {
+            const TAG: ::std::option::Option<&'static ::std::primitive::str> = #static_tag;
+
+            const ATTRIBUTES: &'static [#krate::AttributeMeta] = &[
+                #(#metas,)*
+            ];
+        }
+
+        #provided_attributes
+
+        #auto_id
+
+        #conversions
+    })
+}
@@ -0,0 +1,95 @@
+//! Render and diff cost of struct components.
+//!
+//! Run with `cargo bench -p yew-struct-component`.
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use yew::prelude::*;
+use yew_struct_component::{Attributes, StructComponent};
+
+const ITERATIONS: u32 = 100_000;
+
+/// Typed fields only, rendered with statically known keys.
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "button")]
+struct StaticKeysChildProps {
+    pub id: Option<AttrValue>,
+    pub class: Option<AttrValue>,
+    pub title: Option<AttrValue>,
+    pub r#type: AttrValue,
+    pub disabled: bool,
+    pub aria_label: Option<AttrValue>,
+}
+
+/// Same fields with an additional attributes map, rendered as an index map.
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "button")]
+struct IndexMapChildProps {
+    pub attributes: Attributes,
+
+    pub id: Option<AttrValue>,
+    pub class: Option<AttrValue>,
+    pub title: Option<AttrValue>,
+    pub r#type: AttrValue,
+    pub disabled: bool,
+    pub aria_label: Option<AttrValue>,
+}
+
+fn bench<T>(name: &str, mut f: impl FnMut() -> T) {
+    // Warm up allocator and caches.
+    for _ in 0..ITERATIONS / 10 {
+        black_box(f());
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+    let elapsed: Duration = start.elapsed();
+
+    println!(
+        "{name:<32} {:>10.1} ns/iter",
+        elapsed.as_nanos() as f64 / f64::from(ITERATIONS)
+    );
+}
+
+fn main() {
+    let static_keys = StaticKeysChildProps {
+        id: Some(AttrValue::Static("button")),
+        class: Some(AttrValue::Static("button button-primary")),
+        title: None,
+        r#type: AttrValue::Static("submit"),
+        disabled: false,
+        aria_label: Some(AttrValue::Static("Submit")),
+    };
+    let index_map = IndexMapChildProps {
+        attributes: Attributes::default(),
+
+        id: Some(AttrValue::Static("button")),
+        class: Some(AttrValue::Static("button button-primary")),
+        title: None,
+        r#type: AttrValue::Static("submit"),
+        disabled: false,
+        aria_label: Some(AttrValue::Static("Submit")),
+    };
+
+    bench("render/static_keys", || {
+        static_keys.to_vtag(Html::default())
+    });
+    bench("render/index_map", || index_map.to_vtag(Html::default()));
+
+    // Yew compares attributes before patching the DOM, so equality is the cost of an unchanged diff.
+    let (old, new) = (
+        static_keys.to_vtag(Html::default()),
+        static_keys.to_vtag(Html::default()),
+    );
+    bench("diff/static_keys", || old.attributes == new.attributes);
+
+    let (old, new) = (
+        index_map.to_vtag(Html::default()),
+        index_map.to_vtag(Html::default()),
+    );
+    bench("diff/index_map", || old.attributes == new.attributes);
+}
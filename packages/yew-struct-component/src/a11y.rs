@@ -0,0 +1,302 @@
+use std::fmt::{self, Display};
+
+use yew::virtual_dom::{VNode, VTag};
+
+use crate::{AriaCurrent, AriaHasPopup, AriaRole, Tristate};
+
+#[derive(Clone, Copy, Debug)]
+enum AriaValue {
+    Any,
+    Boolean,
+    BooleanOrUndefined,
+    Tristate,
+    Integer,
+    Number,
+    IdReference,
+    IdReferences,
+    Token(&'static [&'static str]),
+    Tokens(&'static [&'static str]),
+}
+
+const ARIA_ATTRIBUTES: &[(&str, AriaValue)] = &[
+    ("aria-activedescendant", AriaValue::IdReference),
+    ("aria-atomic", AriaValue::Boolean),
+    (
+        "aria-autocomplete",
+        AriaValue::Token(&["inline", "list", "both", "none"]),
+    ),
+    ("aria-braillelabel", AriaValue::Any),
+    ("aria-brailleroledescription", AriaValue::Any),
+    ("aria-busy", AriaValue::Boolean),
+    ("aria-checked", AriaValue::Tristate),
+    ("aria-colcount", AriaValue::Integer),
+    ("aria-colindex", AriaValue::Integer),
+    ("aria-colindextext", AriaValue::Any),
+    ("aria-colspan", AriaValue::Integer),
+    ("aria-controls", AriaValue::IdReferences),
+    ("aria-current", AriaValue::Token(AriaCurrent::VALUES)),
+    ("aria-describedby", AriaValue::IdReferences),
+    ("aria-description", AriaValue::Any),
+    ("aria-details", AriaValue::IdReferences),
+    ("aria-disabled", AriaValue::Boolean),
+    (
+        "aria-dropeffect",
+        AriaValue::Tokens(&["copy", "execute", "link", "move", "none", "popup"]),
+    ),
+    ("aria-errormessage", AriaValue::IdReferences),
+    ("aria-expanded", AriaValue::BooleanOrUndefined),
+    ("aria-flowto", AriaValue::IdReferences),
+    ("aria-grabbed", AriaValue::BooleanOrUndefined),
+    ("aria-haspopup", AriaValue::Token(AriaHasPopup::VALUES)),
+    ("aria-hidden", AriaValue::BooleanOrUndefined),
+    (
+        "aria-invalid",
+        AriaValue::Token(&["grammar", "false", "spelling", "true"]),
+    ),
+    ("aria-keyshortcuts", AriaValue::Any),
+    ("aria-label", AriaValue::Any),
+    ("aria-labelledby", AriaValue::IdReferences),
+    ("aria-level", AriaValue::Integer),
+    (
+        "aria-live",
+        AriaValue::Token(&["assertive", "off", "polite"]),
+    ),
+    ("aria-modal", AriaValue::Boolean),
+    ("aria-multiline", AriaValue::Boolean),
+    ("aria-multiselectable", AriaValue::Boolean),
+    (
+        "aria-orientation",
+        AriaValue::Token(&["horizontal", "vertical", "undefined"]),
+    ),
+    ("aria-owns", AriaValue::IdReferences),
+    ("aria-placeholder", AriaValue::Any),
+    ("aria-posinset", AriaValue::Integer),
+    ("aria-pressed", AriaValue::Tristate),
+    ("aria-readonly", AriaValue::Boolean),
+    (
+        "aria-relevant",
+        AriaValue::Tokens(&["additions", "removals", "text", "all"]),
+    ),
+    ("aria-required", AriaValue::Boolean),
+    ("aria-roledescription", AriaValue::Any),
+    ("aria-rowcount", AriaValue::Integer),
+    ("aria-rowindex", AriaValue::Integer),
+    ("aria-rowindextext", AriaValue::Any),
+    ("aria-rowspan", AriaValue::Integer),
+    ("aria-selected", AriaValue::BooleanOrUndefined),
+    ("aria-setsize", AriaValue::Integer),
+    (
+        "aria-sort",
+        AriaValue::Token(&["ascending", "descending", "none", "other"]),
+    ),
+    ("aria-valuemax", AriaValue::Number),
+    ("aria-valuemin", AriaValue::Number),
+    ("aria-valuenow", AriaValue::Number),
+    ("aria-valuetext", AriaValue::Any),
+];
+
+impl AriaValue {
+    fn is_valid(self, value: &str) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Boolean => ["true", "false"].contains(&value),
+            Self::BooleanOrUndefined => ["true", "false", "undefined"].contains(&value),
+            Self::Tristate => Tristate::VALUES.contains(&value) || value == "undefined",
+            Self::Integer => value.parse::<i64>().is_ok(),
+            Self::Number => value.parse::<f64>().is_ok(),
+            Self::IdReference => !value.is_empty() && !value.contains(char::is_whitespace),
+            Self::IdReferences => !value.trim().is_empty(),
+            Self::Token(tokens) => tokens.contains(&value),
+            Self::Tokens(tokens) => value
+                .split_whitespace()
+                .all(|value| tokens.contains(&value)),
+        }
+    }
+}
+
+/// Accessibility problem found in rendered output.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct A11yIssue {
+    pub tag: String,
+    pub message: String,
+}
+
+impl Display for A11yIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}>: {}", self.tag, self.message)
+    }
+}
+
+/// Whether the node renders text, or [`None`] if it can't be known before rendering.
+fn has_text(node: &VNode) -> Option<bool> {
+    match node {
+        VNode::VTag(tag) => match tag.tag() {
+            "img" => Some(
+                tag.attributes
+                    .iter()
+                    .any(|(key, value)| key == "alt" && !value.trim().is_empty()),
+            ),
+            _ => tag.children().map_or(Some(false), has_text),
+        },
+        VNode::VText(text) => Some(!text.text.trim().is_empty()),
+        VNode::VList(list) => list.iter().try_fold(false, |has, node| {
+            has_text(node).map(|has_node| has || has_node)
+        }),
+        _ => None,
+    }
+}
+
+/// Check a rendered struct component for common accessibility mistakes.
+pub fn a11y_issues(tag: &VTag) -> Vec<A11yIssue> {
+    let mut issues = vec![];
+    let mut issue = |message: String| {
+        issues.push(A11yIssue {
+            tag: tag.tag().to_string(),
+            message,
+        })
+    };
+
+    let attribute = |name: &str| {
+        tag.attributes
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value)
+    };
+    let has_attribute = |name: &str| attribute(name).is_some_and(|value| !value.trim().is_empty());
+
+    for (key, value) in tag.attributes.iter() {
+        if key == "role" {
+            for role in value.split_whitespace() {
+                if !AriaRole::VALUES.contains(&role) {
+                    issue(format!("unknown role `{role}`"));
+                }
+            }
+        } else if key.starts_with("aria-") {
+            match ARIA_ATTRIBUTES.iter().find(|(name, _)| *name == key) {
+                Some((_, kind)) => {
+                    if !kind.is_valid(value) {
+                        issue(format!("invalid value `{value}` for `{key}`"));
+                    }
+                }
+                None => issue(format!("unknown attribute `{key}`")),
+            }
+        }
+    }
+
+    let tag_name = tag.tag();
+    if tag_name == "img" && attribute("alt").is_none() {
+        issue("missing `alt` attribute".to_string());
+    }
+    if tag_name == "button"
+        && !has_attribute("aria-label")
+        && !has_attribute("aria-labelledby")
+        && !has_attribute("title")
+        && tag.children().map_or(Some(false), has_text) == Some(false)
+    {
+        issue("missing accessible name".to_string());
+    }
+
+    issues
+}
+
+/// Start tags in an HTML string with their attributes.
+fn start_tags(html: &str) -> Vec<(&str, Vec<(&str, &str)>)> {
+    let mut tags = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+        if rest.starts_with(['/', '!', '?']) {
+            continue;
+        }
+
+        let end = rest
+            .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+            .unwrap_or(rest.len());
+        let name = &rest[..end];
+        rest = &rest[end..];
+
+        let mut attributes = vec![];
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+            if rest.is_empty() || rest.starts_with('>') {
+                break;
+            }
+
+            let end = rest
+                .find(|c: char| c.is_whitespace() || c == '=' || c == '>' || c == '/')
+                .unwrap_or(rest.len());
+            let key = &rest[..end];
+            rest = &rest[end..];
+
+            let value = if let Some(value) = rest.strip_prefix('=') {
+                let (value, remaining) = match value.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let value = &value[1..];
+                        let end = value.find(quote).unwrap_or(value.len());
+                        (&value[..end], &value[(end + 1).min(value.len())..])
+                    }
+                    _ => {
+                        let end = value
+                            .find(|c: char| c.is_whitespace() || c == '>')
+                            .unwrap_or(value.len());
+                        (&value[..end], &value[end..])
+                    }
+                };
+                rest = remaining;
+                value
+            } else {
+                ""
+            };
+
+            attributes.push((key, value));
+        }
+
+        tags.push((name, attributes));
+    }
+
+    tags
+}
+
+/// Check server-side rendered HTML for ARIA id references to elements that don't exist.
+pub fn a11y_reference_issues(html: &str) -> Vec<A11yIssue> {
+    let tags = start_tags(html);
+
+    let ids = tags
+        .iter()
+        .flat_map(|(_, attributes)| attributes)
+        .filter(|(key, _)| *key == "id")
+        .map(|(_, value)| *value)
+        .collect::<Vec<_>>();
+
+    let mut issues = vec![];
+    for (tag, attributes) in &tags {
+        for (key, value) in attributes {
+            let is_reference = ARIA_ATTRIBUTES.iter().any(|(name, kind)| {
+                name == key && matches!(kind, AriaValue::IdReference | AriaValue::IdReferences)
+            });
+            if !is_reference {
+                continue;
+            }
+
+            for id in value.split_whitespace() {
+                if !ids.contains(&id) {
+                    issues.push(A11yIssue {
+                        tag: tag.to_string(),
+                        message: format!("`{key}` references missing id `{id}`"),
+                    });
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+/// Log accessibility issues of a rendered struct component.
+#[cfg(debug_assertions)]
+pub(crate) fn report(tag: &VTag) {
+    for issue in a11y_issues(tag) {
+        log::warn!("{issue}");
+    }
+}
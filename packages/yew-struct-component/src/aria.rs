@@ -0,0 +1,248 @@
+use yew::AttrValue;
+
+use crate::{AttrValueEnum, attributes::Attributes};
+
+/// WAI-ARIA roles, excluding abstract roles.
+#[derive(AttrValueEnum, Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[attr_value(crate = "crate", rename_all = "lowercase")]
+pub enum AriaRole {
+    Alert,
+    AlertDialog,
+    Application,
+    Article,
+    Banner,
+    Blockquote,
+    Button,
+    Caption,
+    Cell,
+    Checkbox,
+    Code,
+    ColumnHeader,
+    ComboBox,
+    Complementary,
+    ContentInfo,
+    Definition,
+    Deletion,
+    Dialog,
+    Directory,
+    Document,
+    Emphasis,
+    Feed,
+    Figure,
+    Form,
+    Generic,
+    Grid,
+    GridCell,
+    Group,
+    Heading,
+    Img,
+    Insertion,
+    Link,
+    List,
+    ListBox,
+    ListItem,
+    Log,
+    Main,
+    Marquee,
+    Math,
+    Menu,
+    MenuBar,
+    MenuItem,
+    MenuItemCheckbox,
+    MenuItemRadio,
+    Meter,
+    Navigation,
+    None,
+    Note,
+    Option,
+    Paragraph,
+    Presentation,
+    ProgressBar,
+    Radio,
+    RadioGroup,
+    Region,
+    Row,
+    RowGroup,
+    RowHeader,
+    ScrollBar,
+    Search,
+    SearchBox,
+    Separator,
+    Slider,
+    SpinButton,
+    Status,
+    Strong,
+    Subscript,
+    Superscript,
+    Switch,
+    Tab,
+    Table,
+    TabList,
+    TabPanel,
+    Term,
+    TextBox,
+    Time,
+    Timer,
+    Toolbar,
+    Tooltip,
+    Tree,
+    TreeGrid,
+    TreeItem,
+}
+
+/// Value of `aria-checked` and `aria-pressed`.
+#[derive(AttrValueEnum, Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[attr_value(crate = "crate", rename_all = "lowercase")]
+pub enum Tristate {
+    False,
+    True,
+    Mixed,
+}
+
+impl From<bool> for Tristate {
+    fn from(value: bool) -> Tristate {
+        if value {
+            Tristate::True
+        } else {
+            Tristate::False
+        }
+    }
+}
+
+/// Value of `aria-current`.
+#[derive(AttrValueEnum, Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[attr_value(crate = "crate", rename_all = "lowercase")]
+pub enum AriaCurrent {
+    False,
+    True,
+    Page,
+    Step,
+    Location,
+    Date,
+    Time,
+}
+
+/// Value of `aria-haspopup`.
+#[derive(AttrValueEnum, Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[attr_value(crate = "crate", rename_all = "lowercase")]
+pub enum AriaHasPopup {
+    False,
+    True,
+    Menu,
+    Listbox,
+    Tree,
+    Grid,
+    Dialog,
+}
+
+/// Space-separated list of element ids, omitted when empty.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IdRefs(pub Vec<AttrValue>);
+
+impl IdRefs {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn to_attr_value(&self) -> Option<AttrValue> {
+        match self.0.as_slice() {
+            [] => None,
+            [id] => Some(id.clone()),
+            ids => Some(AttrValue::from(
+                ids.iter()
+                    .map(AttrValue::as_str)
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            )),
+        }
+    }
+}
+
+impl<T: Into<AttrValue>> FromIterator<T> for IdRefs {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> IdRefs {
+        IdRefs(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<AttrValue>> From<Vec<T>> for IdRefs {
+    fn from(value: Vec<T>) -> IdRefs {
+        IdRefs::from_iter(value)
+    }
+}
+
+impl<T: Into<AttrValue>, const N: usize> From<[T; N]> for IdRefs {
+    fn from(value: [T; N]) -> IdRefs {
+        IdRefs::from_iter(value)
+    }
+}
+
+/// Typed ARIA attributes, convertible into [`Attributes`].
+///
+/// Fields are named after the attribute without the `aria-` prefix, `None` and empty fields are omitted.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Aria {
+    pub role: Option<AriaRole>,
+
+    pub label: Option<AttrValue>,
+    pub labelledby: IdRefs,
+    pub describedby: IdRefs,
+    pub controls: IdRefs,
+    pub owns: IdRefs,
+    pub activedescendant: Option<AttrValue>,
+
+    pub current: Option<AriaCurrent>,
+    pub haspopup: Option<AriaHasPopup>,
+    pub checked: Option<Tristate>,
+    pub pressed: Option<Tristate>,
+
+    pub busy: Option<bool>,
+    pub disabled: Option<bool>,
+    pub expanded: Option<bool>,
+    pub hidden: Option<bool>,
+    pub modal: Option<bool>,
+    pub readonly: Option<bool>,
+    pub required: Option<bool>,
+    pub selected: Option<bool>,
+}
+
+impl From<Aria> for Attributes {
+    fn from(value: Aria) -> Attributes {
+        let boolean = |value: Option<bool>| {
+            value.map(|value| AttrValue::Static(if value { "true" } else { "false" }))
+        };
+
+        let entries: [(&'static str, Option<AttrValue>); 19] = [
+            ("role", value.role.map(AttrValue::from)),
+            ("aria-label", value.label),
+            ("aria-labelledby", value.labelledby.to_attr_value()),
+            ("aria-describedby", value.describedby.to_attr_value()),
+            ("aria-controls", value.controls.to_attr_value()),
+            ("aria-owns", value.owns.to_attr_value()),
+            ("aria-activedescendant", value.activedescendant),
+            ("aria-current", value.current.map(AttrValue::from)),
+            ("aria-haspopup", value.haspopup.map(AttrValue::from)),
+            ("aria-checked", value.checked.map(AttrValue::from)),
+            ("aria-pressed", value.pressed.map(AttrValue::from)),
+            ("aria-busy", boolean(value.busy)),
+            ("aria-disabled", boolean(value.disabled)),
+            ("aria-expanded", boolean(value.expanded)),
+            ("aria-hidden", boolean(value.hidden)),
+            ("aria-modal", boolean(value.modal)),
+            ("aria-readonly", boolean(value.readonly)),
+            ("aria-required", boolean(value.required)),
+            ("aria-selected", boolean(value.selected)),
+        ];
+
+        // Omitted fields are skipped, so they don't override defaults.
+        entries
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|value| (key, value)))
+            .collect()
+    }
+}
+
+impl From<&Aria> for Attributes {
+    fn from(value: &Aria) -> Attributes {
+        value.clone().into()
+    }
+}
@@ -1,242 +1,736 @@
 use std::{
-    collections::HashMap,
+    borrow::Cow,
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    iter::{FlatMap, Flatten},
     ops::Deref,
-    option::{IntoIter, Iter, IterMut},
+    option::IntoIter,
+    rc::Rc,
+    str::FromStr,
 };
 
+use indexmap::IndexMap;
 use yew::{AttrValue, html::IntoPropValue};
 
+use crate::{aria::Aria, sanitize::Policy};
+
+/// SVG attributes with case-sensitive names, sorted case-insensitively.
+const SVG_ATTRIBUTES: &[&str] = &[
+    "attributeName",
+    "attributeType",
+    "baseFrequency",
+    "baseProfile",
+    "calcMode",
+    "clipPathUnits",
+    "diffuseConstant",
+    "edgeMode",
+    "filterUnits",
+    "glyphRef",
+    "gradientTransform",
+    "gradientUnits",
+    "kernelMatrix",
+    "kernelUnitLength",
+    "keyPoints",
+    "keySplines",
+    "keyTimes",
+    "lengthAdjust",
+    "limitingConeAngle",
+    "markerHeight",
+    "markerUnits",
+    "markerWidth",
+    "maskContentUnits",
+    "maskUnits",
+    "numOctaves",
+    "pathLength",
+    "patternContentUnits",
+    "patternTransform",
+    "patternUnits",
+    "pointsAtX",
+    "pointsAtY",
+    "pointsAtZ",
+    "preserveAlpha",
+    "preserveAspectRatio",
+    "primitiveUnits",
+    "refX",
+    "refY",
+    "repeatCount",
+    "repeatDur",
+    "requiredExtensions",
+    "requiredFeatures",
+    "specularConstant",
+    "specularExponent",
+    "spreadMethod",
+    "startOffset",
+    "stdDeviation",
+    "stitchTiles",
+    "surfaceScale",
+    "systemLanguage",
+    "tableValues",
+    "targetX",
+    "targetY",
+    "textLength",
+    "viewBox",
+    "viewTarget",
+    "xChannelSelector",
+    "yChannelSelector",
+    "zoomAndPan",
+];
+
+fn cmp_ignore_ascii_case(a: &str, b: &str) -> Ordering {
+    a.bytes()
+        .map(|byte| byte.to_ascii_lowercase())
+        .cmp(b.bytes().map(|byte| byte.to_ascii_lowercase()))
+}
+
+fn svg_attribute(name: &str) -> Option<&'static str> {
+    SVG_ATTRIBUTES
+        .binary_search_by(|attribute| cmp_ignore_ascii_case(attribute, name))
+        .ok()
+        .map(|index| SVG_ATTRIBUTES[index])
+}
+
+/// Lowercase an attribute name, except for the camel case names of SVG attributes.
+fn normalize_key(key: &str) -> Cow<'_, str> {
+    if let Some(attribute) = svg_attribute(key) {
+        Cow::Borrowed(attribute)
+    } else if key.bytes().any(|byte| byte.is_ascii_uppercase()) {
+        Cow::Owned(key.to_ascii_lowercase())
+    } else {
+        Cow::Borrowed(key)
+    }
+}
+
+fn normalize_name(name: AttrValue) -> AttrValue {
+    if let Some(attribute) = svg_attribute(&name) {
+        AttrValue::Static(attribute)
+    } else if name.bytes().any(|byte| byte.is_ascii_uppercase()) {
+        AttrValue::from(name.to_ascii_lowercase())
+    } else {
+        name
+    }
+}
+
+/// Whether a spelling of a name takes precedence over another spelling of the same name.
+///
+/// The normalized spelling wins, otherwise the first spelling in lexicographic order, so the result does not depend on
+/// iteration order.
+fn takes_precedence(spelling: &str, other: &str, name: &str) -> bool {
+    (spelling != name, spelling) < (other != name, other)
+}
+
+/// Additional attributes, shared so clones are cheap.
+///
+/// Names are case-insensitive, so they are lowercased on insert, except for the camel case names of SVG attributes.
+/// When a conversion or [`Extend`] contains several spellings of a name, the normalized spelling is kept.
+///
+/// Maps, vectors and arrays of pairs convert directly. Other iterators of pairs, including adapters, can be collected
+/// or wrapped in [`AttributePairs`].
 #[derive(Clone, Debug, Default, PartialEq)]
-pub struct Attributes(Option<HashMap<AttrValue, Option<AttrValue>>>);
+pub struct Attributes(Option<Rc<HashMap<AttrValue, Option<AttrValue>>>>);
 
 impl Attributes {
-    pub fn with_defaults<I: Into<Attributes>>(mut self, defaults: I) -> Attributes {
-        let defaults: Attributes = defaults.into();
+    /// Returns the value of an attribute, an inner [`None`] is not rendered.
+    pub fn get(&self, key: &str) -> Option<&Option<AttrValue>> {
+        self.0
+            .as_ref()
+            .and_then(|map| map.get(normalize_key(key).as_ref()))
+    }
 
-        self.0 = match (self.0, defaults.0) {
-            (Some(map), Some(defaults)) => Some(defaults.into_iter().chain(map).collect()),
-            (Some(map), None) => Some(map),
-            (None, Some(defaults)) => Some(defaults),
-            (None, None) => None,
-        };
+    pub fn contains(&self, key: &str) -> bool {
+        self.0
+            .as_ref()
+            .is_some_and(|map| map.contains_key(normalize_key(key).as_ref()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.as_ref().map_or(0, |map| map.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> AttributesIter<'_> {
+        self.into_iter()
+    }
+
+    pub fn iter_mut(&mut self) -> AttributesIterMut<'_> {
+        self.into_iter()
+    }
+
+    /// Inserts an attribute, returning the previous value if it was present.
+    pub fn insert<K: Into<AttrValue>, V: IntoAttributeValue>(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Option<Option<AttrValue>> {
+        self.map_mut()
+            .insert(normalize_name(key.into()), value.into_attribute_value())
+    }
+
+    /// Removes an attribute, returning its value if it was present.
+    pub fn remove(&mut self, key: &str) -> Option<Option<AttrValue>> {
+        if !self.contains(key) {
+            return None;
+        }
+
+        let value = self.map_mut().remove(normalize_key(key).as_ref());
+        self.normalize();
+        value
+    }
+
+    pub fn retain<F: FnMut(&AttrValue, &mut Option<AttrValue>) -> bool>(&mut self, f: F) {
+        if self.0.is_some() {
+            self.map_mut().retain(f);
+            self.normalize();
+        }
+    }
+
+    /// Removes an attribute, returning its value if it would be rendered.
+    pub fn take(&mut self, key: &str) -> Option<AttrValue> {
+        self.remove(key).flatten()
+    }
+
+    /// Removes a boolean attribute, which is set unless it is omitted or `"false"`.
+    pub fn take_bool(&mut self, key: &str) -> bool {
+        self.take(key).is_some_and(|value| value != "false")
+    }
+
+    /// Removes an attribute and parses its value.
+    pub fn take_parsed<T: FromStr>(&mut self, key: &str) -> Result<Option<T>, T::Err> {
+        self.take(key).map(|value| value.parse()).transpose()
+    }
 
+    /// Splits into the attributes with one of `keys` and the remaining attributes.
+    pub fn split(self, keys: &[&str]) -> (Attributes, Attributes) {
+        self.into_iter()
+            .partition(|(key, _)| keys.iter().any(|name| normalize_key(name) == key.as_str()))
+    }
+
+    /// Removes attributes which are not allowed by `policy`, ignoring its per tag rules.
+    pub fn sanitize(&mut self, policy: &Policy) {
+        self.retain(|key, value| policy.allows(None, key, value.as_deref()));
+    }
+
+    /// Removes attributes which are not allowed by `policy` on `tag`.
+    pub fn sanitize_for(&mut self, tag: &str, policy: &Policy) {
+        self.retain(|key, value| policy.allows(Some(tag), key, value.as_deref()));
+    }
+
+    /// Merges attributes, keeping existing values over `defaults`.
+    ///
+    /// Values present on both sides are combined according to [`Merge::for_attribute`].
+    /// Names are normalized on both sides, so `"ID"` in `defaults` is replaced by `"id"` in `self`.
+    pub fn with_defaults<I: Into<Attributes>>(self, defaults: I) -> Attributes {
+        self.with_defaults_by(defaults, Merge::for_attribute)
+    }
+
+    /// Merges attributes like [`Attributes::with_defaults`], choosing how values are combined per attribute.
+    pub fn with_defaults_by<I: Into<Attributes>, F: Fn(&str) -> Merge>(
+        self,
+        defaults: I,
+        merge: F,
+    ) -> Attributes {
+        let mut attributes: Attributes = defaults.into();
+        if attributes.is_empty() {
+            return self;
+        }
+
+        for (key, value) in self {
+            let value = match (attributes.get(&key), value) {
+                (Some(Some(default)), Some(value)) => Some(merge(&key).apply(default, &value)),
+                (_, value) => value,
+            };
+
+            attributes.insert(key, value);
+        }
+
+        attributes
+    }
+
+    /// Merges attributes, replacing existing values with `overrides`.
+    pub fn with_overrides<I: Into<Attributes>>(mut self, overrides: I) -> Attributes {
+        self.merge(overrides);
         self
     }
-}
 
-impl Deref for Attributes {
-    type Target = Option<HashMap<AttrValue, Option<AttrValue>>>;
+    /// Merges attributes in place, replacing existing values with `other`.
+    pub fn merge<I: Into<Attributes>>(&mut self, other: I) {
+        let other: Attributes = other.into();
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+        match (&mut self.0, other.0) {
+            (Some(map), Some(other)) => Rc::make_mut(map).extend(Rc::unwrap_or_clone(other)),
+            (None, Some(other)) => self.0 = Some(other),
+            (_, None) => {}
+        }
+    }
+
+    // Copy on write, so clones sharing the map are not affected.
+    fn map_mut(&mut self) -> &mut HashMap<AttrValue, Option<AttrValue>> {
+        Rc::make_mut(self.0.get_or_insert_with(Default::default))
     }
-}
 
-impl From<HashMap<AttrValue, Option<AttrValue>>> for Attributes {
-    fn from(value: HashMap<AttrValue, Option<AttrValue>>) -> Attributes {
-        Attributes(Some(value))
+    // Empty attributes are always stored as `None`, so they compare equal to the default.
+    fn normalize(&mut self) {
+        if self.0.as_ref().is_some_and(|map| map.is_empty()) {
+            self.0 = None;
+        }
     }
 }
 
-impl From<HashMap<AttrValue, AttrValue>> for Attributes {
-    fn from(value: HashMap<AttrValue, AttrValue>) -> Attributes {
-        Attributes(Some(
-            value
-                .into_iter()
-                .map(|(key, value)| (key, Some(value)))
-                .collect(),
-        ))
+/// How a value is combined with its default in [`Attributes::with_defaults`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Merge {
+    /// The value replaces the default.
+    Replace,
+    /// Space-separated tokens of the value are appended to the default, without duplicates.
+    Tokens,
+    /// Style declarations of the value replace those of the default.
+    Style,
+}
+
+impl Merge {
+    /// Combine `class` as tokens and `style` as declarations, replace other attributes.
+    pub fn for_attribute(name: &str) -> Merge {
+        match name {
+            "class" => Merge::Tokens,
+            "style" => Merge::Style,
+            _ => Merge::Replace,
+        }
+    }
+
+    pub fn apply(self, default: &AttrValue, value: &AttrValue) -> AttrValue {
+        match self {
+            Merge::Replace => value.clone(),
+            Merge::Tokens => {
+                let mut tokens = default.split_ascii_whitespace().collect::<Vec<_>>();
+                for token in value.split_ascii_whitespace() {
+                    if !tokens.contains(&token) {
+                        tokens.push(token);
+                    }
+                }
+
+                AttrValue::from(tokens.join(" "))
+            }
+            #[cfg(feature = "style")]
+            Merge::Style => AttrValue::from(
+                yew_style::Style::from(value.to_string())
+                    .into_structured()
+                    .with_defaults(yew_style::Style::from(default.to_string()).into_structured()),
+            ),
+            // Later declarations take precedence, so appending is equivalent without parsing.
+            #[cfg(not(feature = "style"))]
+            Merge::Style => {
+                let default = default.trim_end();
+                if default.is_empty() || default.ends_with(';') {
+                    AttrValue::from(format!("{default} {value}").trim().to_string())
+                } else {
+                    AttrValue::from(format!("{default}; {value}"))
+                }
+            }
+        }
     }
 }
 
-impl From<HashMap<String, Option<String>>> for Attributes {
-    fn from(value: HashMap<String, Option<String>>) -> Attributes {
-        Attributes(Some(
-            value
-                .into_iter()
-                .map(|(key, value)| (AttrValue::from(key), value.map(AttrValue::from)))
-                .collect(),
-        ))
+impl Deref for Attributes {
+    type Target = Option<Rc<HashMap<AttrValue, Option<AttrValue>>>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
     }
 }
 
-impl From<HashMap<String, String>> for Attributes {
-    fn from(value: HashMap<String, String>) -> Attributes {
-        Attributes(Some(
-            value
-                .into_iter()
-                .map(|(key, value)| (AttrValue::from(key), Some(AttrValue::from(value))))
-                .collect(),
-        ))
+impl<K: Into<AttrValue>, V: IntoAttributeValue, S> From<HashMap<K, V, S>> for Attributes {
+    fn from(value: HashMap<K, V, S>) -> Attributes {
+        Attributes::from_iter(value)
     }
 }
 
-impl<const N: usize> From<[(AttrValue, Option<AttrValue>); N]> for Attributes {
-    fn from(value: [(AttrValue, Option<AttrValue>); N]) -> Attributes {
-        Attributes(Some(HashMap::from_iter(value)))
+impl<K: Into<AttrValue>, V: IntoAttributeValue> From<BTreeMap<K, V>> for Attributes {
+    fn from(value: BTreeMap<K, V>) -> Attributes {
+        Attributes::from_iter(value)
     }
 }
 
-impl<const N: usize> From<[(AttrValue, AttrValue); N]> for Attributes {
-    fn from(value: [(AttrValue, AttrValue); N]) -> Attributes {
-        Attributes(Some(HashMap::from_iter(
-            value.map(|(key, value)| (key, Some(value))),
-        )))
+impl<K: Into<AttrValue>, V: IntoAttributeValue, S> From<IndexMap<K, V, S>> for Attributes {
+    fn from(value: IndexMap<K, V, S>) -> Attributes {
+        Attributes::from_iter(value)
     }
 }
 
-impl<const N: usize> From<[(&str, Option<&str>); N]> for Attributes {
-    fn from(value: [(&str, Option<&str>); N]) -> Attributes {
-        Attributes(Some(HashMap::from_iter(value.map(|(key, value)| {
-            (
-                AttrValue::from(key.to_string()),
-                value.map(|value| AttrValue::from(value.to_string())),
-            )
-        }))))
+impl<K: Into<AttrValue>, V: IntoAttributeValue> From<Vec<(K, V)>> for Attributes {
+    fn from(value: Vec<(K, V)>) -> Attributes {
+        Attributes::from_iter(value)
     }
 }
 
-impl<const N: usize> From<[(&str, &str); N]> for Attributes {
-    fn from(value: [(&str, &str); N]) -> Attributes {
-        Attributes(Some(HashMap::from_iter(value.map(|(key, value)| {
-            (
-                AttrValue::from(key.to_string()),
-                Some(AttrValue::from(value.to_string())),
-            )
-        }))))
+impl<K: Into<AttrValue>, V: IntoAttributeValue, const N: usize> From<[(K, V); N]> for Attributes {
+    fn from(value: [(K, V); N]) -> Attributes {
+        Attributes::from_iter(value)
     }
 }
 
-impl<const N: usize> From<[(&str, Option<String>); N]> for Attributes {
-    fn from(value: [(&str, Option<String>); N]) -> Attributes {
-        Attributes(Some(HashMap::from_iter(value.map(|(key, value)| {
-            (AttrValue::from(key.to_string()), value.map(AttrValue::from))
-        }))))
+impl From<Option<Attributes>> for Attributes {
+    fn from(value: Option<Attributes>) -> Attributes {
+        value.unwrap_or_default()
     }
 }
 
-impl<const N: usize> From<[(&str, String); N]> for Attributes {
-    fn from(value: [(&str, String); N]) -> Attributes {
-        Attributes(Some(HashMap::from_iter(value.map(|(key, value)| {
-            (
-                AttrValue::from(key.to_string()),
-                Some(AttrValue::from(value)),
-            )
-        }))))
+/// Any iterator of attribute names and values, converted to [`Attributes`].
+///
+/// Coherence rules out a blanket conversion from every [`IntoIterator`]: it would overlap with the conversion of
+/// [`Attributes`] to itself, and the orphan rule forbids it for Yew's [`IntoPropValue`]. Wrap other iterators instead,
+/// e.g. `attributes={AttributePairs(items.iter().map(...))}`.
+#[derive(Clone, Debug)]
+pub struct AttributePairs<I>(pub I);
+
+impl<I: IntoIterator<Item = (K, V)>, K: Into<AttrValue>, V: IntoAttributeValue>
+    From<AttributePairs<I>> for Attributes
+{
+    fn from(value: AttributePairs<I>) -> Attributes {
+        Attributes::from_iter(value.0)
     }
 }
 
-impl<const N: usize> From<[(String, Option<String>); N]> for Attributes {
-    fn from(value: [(String, Option<String>); N]) -> Attributes {
-        Attributes(Some(HashMap::from_iter(value.map(|(key, value)| {
-            (AttrValue::from(key), value.map(AttrValue::from))
-        }))))
+impl<K: Into<AttrValue>, V: IntoAttributeValue> Extend<(K, V)> for Attributes {
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        // Spellings of each name in this batch, to resolve collisions deterministically.
+        let mut spellings: HashMap<AttrValue, AttrValue> = HashMap::new();
+
+        for (key, value) in iter {
+            let key: AttrValue = key.into();
+            let name = normalize_name(key.clone());
+
+            if spellings
+                .get(&name)
+                .is_some_and(|spelling| takes_precedence(spelling, &key, &name))
+            {
+                continue;
+            }
+
+            self.map_mut()
+                .insert(name.clone(), value.into_attribute_value());
+            spellings.insert(name, key);
+        }
     }
 }
 
-impl<const N: usize> From<[(String, String); N]> for Attributes {
-    fn from(value: [(String, String); N]) -> Attributes {
-        Attributes(Some(HashMap::from_iter(value.map(|(key, value)| {
-            (AttrValue::from(key), Some(AttrValue::from(value)))
-        }))))
+impl<K: Into<AttrValue>, V: IntoAttributeValue> FromIterator<(K, V)> for Attributes {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Attributes {
+        let mut attributes = Attributes::default();
+        attributes.extend(iter);
+        attributes
     }
 }
 
+pub type AttributesIter<'a> = Flatten<IntoIter<&'a HashMap<AttrValue, Option<AttrValue>>>>;
+
+pub type AttributesIterMut<'a> = Flatten<IntoIter<&'a mut HashMap<AttrValue, Option<AttrValue>>>>;
+
 impl<'a> IntoIterator for &'a Attributes {
-    type Item = &'a HashMap<AttrValue, Option<AttrValue>>;
-    type IntoIter = Iter<'a, HashMap<AttrValue, Option<AttrValue>>>;
+    type Item = (&'a AttrValue, &'a Option<AttrValue>);
+    type IntoIter = AttributesIter<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.iter()
+        self.0.as_deref().into_iter().flatten()
     }
 }
 
 impl<'a> IntoIterator for &'a mut Attributes {
-    type Item = &'a mut HashMap<AttrValue, Option<AttrValue>>;
-    type IntoIter = IterMut<'a, HashMap<AttrValue, Option<AttrValue>>>;
+    type Item = (&'a AttrValue, &'a mut Option<AttrValue>);
+    type IntoIter = AttributesIterMut<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.iter_mut()
+        // Copy on write, so clones sharing the map are not affected.
+        self.0.as_mut().map(Rc::make_mut).into_iter().flatten()
     }
 }
 
 impl IntoIterator for Attributes {
-    type Item = HashMap<AttrValue, Option<AttrValue>>;
-    type IntoIter = IntoIter<HashMap<AttrValue, Option<AttrValue>>>;
+    type Item = (AttrValue, Option<AttrValue>);
+    type IntoIter = FlatMap<
+        IntoIter<Rc<HashMap<AttrValue, Option<AttrValue>>>>,
+        HashMap<AttrValue, Option<AttrValue>>,
+        fn(Rc<HashMap<AttrValue, Option<AttrValue>>>) -> HashMap<AttrValue, Option<AttrValue>>,
+    >;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.0.into_iter().flat_map(Rc::unwrap_or_clone)
     }
 }
 
-impl IntoPropValue<Attributes> for HashMap<AttrValue, Option<AttrValue>> {
+impl<K: Into<AttrValue>, V: IntoAttributeValue, S> IntoPropValue<Attributes> for HashMap<K, V, S> {
     fn into_prop_value(self) -> Attributes {
         self.into()
     }
 }
 
-impl IntoPropValue<Attributes> for HashMap<AttrValue, AttrValue> {
+impl<K: Into<AttrValue>, V: IntoAttributeValue> IntoPropValue<Attributes> for BTreeMap<K, V> {
     fn into_prop_value(self) -> Attributes {
         self.into()
     }
 }
 
-impl IntoPropValue<Attributes> for HashMap<String, Option<String>> {
+impl<K: Into<AttrValue>, V: IntoAttributeValue, S> IntoPropValue<Attributes> for IndexMap<K, V, S> {
     fn into_prop_value(self) -> Attributes {
         self.into()
     }
 }
 
-impl IntoPropValue<Attributes> for HashMap<String, String> {
+impl<K: Into<AttrValue>, V: IntoAttributeValue> IntoPropValue<Attributes> for Vec<(K, V)> {
     fn into_prop_value(self) -> Attributes {
         self.into()
     }
 }
 
-impl<const N: usize> IntoPropValue<Attributes> for [(AttrValue, Option<AttrValue>); N] {
+impl<K: Into<AttrValue>, V: IntoAttributeValue, const N: usize> IntoPropValue<Attributes>
+    for [(K, V); N]
+{
     fn into_prop_value(self) -> Attributes {
         self.into()
     }
 }
 
-impl<const N: usize> IntoPropValue<Attributes> for [(AttrValue, AttrValue); N] {
+impl<I: IntoIterator<Item = (K, V)>, K: Into<AttrValue>, V: IntoAttributeValue>
+    IntoPropValue<Attributes> for AttributePairs<I>
+{
     fn into_prop_value(self) -> Attributes {
         self.into()
     }
 }
 
-impl<const N: usize> IntoPropValue<Attributes> for [(&str, Option<&str>); N] {
+impl IntoPropValue<Attributes> for Aria {
     fn into_prop_value(self) -> Attributes {
         self.into()
     }
 }
 
-impl<const N: usize> IntoPropValue<Attributes> for [(&str, &str); N] {
+impl IntoPropValue<Attributes> for Option<Attributes> {
     fn into_prop_value(self) -> Attributes {
         self.into()
     }
 }
 
-impl<const N: usize> IntoPropValue<Attributes> for [(&str, Option<String>); N] {
-    fn into_prop_value(self) -> Attributes {
-        self.into()
+/// Values which can be used for an attribute, [`None`] omits the attribute.
+pub trait IntoAttributeValue {
+    fn into_attribute_value(self) -> Option<AttrValue>;
+}
+
+impl IntoAttributeValue for AttrValue {
+    fn into_attribute_value(self) -> Option<AttrValue> {
+        Some(self)
     }
 }
 
-impl<const N: usize> IntoPropValue<Attributes> for [(&str, String); N] {
-    fn into_prop_value(self) -> Attributes {
-        self.into()
+impl IntoAttributeValue for &AttrValue {
+    fn into_attribute_value(self) -> Option<AttrValue> {
+        Some(self.clone())
     }
 }
 
-impl<const N: usize> IntoPropValue<Attributes> for [(String, Option<String>); N] {
-    fn into_prop_value(self) -> Attributes {
-        self.into()
+impl IntoAttributeValue for &str {
+    fn into_attribute_value(self) -> Option<AttrValue> {
+        Some(AttrValue::from(self.to_string()))
     }
 }
 
-impl<const N: usize> IntoPropValue<Attributes> for [(String, String); N] {
-    fn into_prop_value(self) -> Attributes {
-        self.into()
+impl IntoAttributeValue for String {
+    fn into_attribute_value(self) -> Option<AttrValue> {
+        Some(AttrValue::from(self))
+    }
+}
+
+impl IntoAttributeValue for &String {
+    fn into_attribute_value(self) -> Option<AttrValue> {
+        Some(AttrValue::from(self.clone()))
+    }
+}
+
+impl IntoAttributeValue for Rc<str> {
+    fn into_attribute_value(self) -> Option<AttrValue> {
+        Some(AttrValue::from(self))
+    }
+}
+
+impl IntoAttributeValue for char {
+    fn into_attribute_value(self) -> Option<AttrValue> {
+        Some(AttrValue::from(self.to_string()))
+    }
+}
+
+/// Boolean attributes are rendered empty when `true` and omitted when `false`.
+impl IntoAttributeValue for bool {
+    fn into_attribute_value(self) -> Option<AttrValue> {
+        self.then_some(AttrValue::Static(""))
+    }
+}
+
+impl<T: IntoAttributeValue> IntoAttributeValue for Option<T> {
+    fn into_attribute_value(self) -> Option<AttrValue> {
+        self.and_then(IntoAttributeValue::into_attribute_value)
+    }
+}
+
+macro_rules! impl_into_attribute_value_for_numbers {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoAttributeValue for $ty {
+                fn into_attribute_value(self) -> Option<AttrValue> {
+                    Some(AttrValue::from(self.to_string()))
+                }
+            }
+        )*
+    };
+}
+
+impl_into_attribute_value_for_numbers!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+/// Empty styles are omitted.
+#[cfg(feature = "style")]
+impl IntoAttributeValue for yew_style::Style {
+    fn into_attribute_value(self) -> Option<AttrValue> {
+        self.as_ref().map(AttrValue::from)
+    }
+}
+
+#[cfg(feature = "style")]
+impl IntoAttributeValue for &yew_style::Style {
+    fn into_attribute_value(self) -> Option<AttrValue> {
+        self.as_ref().map(AttrValue::from)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use std::fmt;
+
+    use serde::{
+        Deserialize, Deserializer, Serialize, Serializer,
+        de::{MapAccess, Visitor},
+    };
+    use yew::AttrValue;
+
+    use super::{Attributes, IntoAttributeValue};
+
+    /// Serialized as a map with sorted names, so the output is stable. Omitted values are `null`.
+    impl Serialize for Attributes {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut entries = self.iter().collect::<Vec<_>>();
+            entries.sort_by_key(|(key, _)| *key);
+
+            serializer.collect_map(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key.as_str(), value.as_deref())),
+            )
+        }
+    }
+
+    /// Deserialized from a map, with values like [`IntoAttributeValue`] for strings, booleans, numbers and `null`.
+    impl<'de> Deserialize<'de> for Attributes {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(AttributesVisitor)
+        }
+    }
+
+    struct AttributesVisitor;
+
+    impl<'de> Visitor<'de> for AttributesVisitor {
+        type Value = Attributes;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a map of attributes")
+        }
+
+        fn visit_none<E>(self) -> Result<Attributes, E> {
+            Ok(Attributes::default())
+        }
+
+        fn visit_unit<E>(self) -> Result<Attributes, E> {
+            Ok(Attributes::default())
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Attributes, D::Error> {
+            deserializer.deserialize_any(AttributesVisitor)
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Attributes, A::Error> {
+            let mut entries = vec![];
+            while let Some((key, Value(value))) = map.next_entry::<String, Value>()? {
+                entries.push((key, value));
+            }
+
+            Ok(Attributes::from_iter(entries))
+        }
+    }
+
+    struct Value(Option<AttrValue>);
+
+    impl<'de> Deserialize<'de> for Value {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(ValueVisitor)
+        }
+    }
+
+    struct ValueVisitor;
+
+    impl<'de> Visitor<'de> for ValueVisitor {
+        type Value = Value;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a string, boolean, number or null")
+        }
+
+        fn visit_bool<E>(self, value: bool) -> Result<Value, E> {
+            Ok(Value(value.into_attribute_value()))
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<Value, E> {
+            Ok(Value(value.into_attribute_value()))
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Value, E> {
+            Ok(Value(value.into_attribute_value()))
+        }
+
+        fn visit_f64<E>(self, value: f64) -> Result<Value, E> {
+            Ok(Value(value.into_attribute_value()))
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Value, E> {
+            Ok(Value(value.into_attribute_value()))
+        }
+
+        fn visit_string<E>(self, value: String) -> Result<Value, E> {
+            Ok(Value(value.into_attribute_value()))
+        }
+
+        fn visit_none<E>(self) -> Result<Value, E> {
+            Ok(Value(None))
+        }
+
+        fn visit_unit<E>(self) -> Result<Value, E> {
+            Ok(Value(None))
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+            deserializer.deserialize_any(ValueVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_svg_attributes_sorted() {
+        assert!(
+            SVG_ATTRIBUTES
+                .windows(2)
+                .all(|pair| cmp_ignore_ascii_case(pair[0], pair[1]) == Ordering::Less)
+        );
     }
 }
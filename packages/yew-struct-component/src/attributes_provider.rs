@@ -0,0 +1,106 @@
+use std::{any::TypeId, collections::HashMap, rc::Rc};
+
+use yew::prelude::*;
+
+use crate::attributes::Attributes;
+
+/// Default attributes for all struct components or for specific struct component types.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AttributeDefaults {
+    all: Attributes,
+    components: Rc<HashMap<TypeId, Attributes>>,
+}
+
+impl AttributeDefaults {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set defaults for all struct components.
+    pub fn all<I: Into<Attributes>>(mut self, attributes: I) -> Self {
+        self.all = attributes.into().with_defaults(self.all);
+        self
+    }
+
+    /// Set defaults for struct components of type `T`, taking precedence over [`AttributeDefaults::all`].
+    pub fn component<T: 'static, I: Into<Attributes>>(mut self, attributes: I) -> Self {
+        let components = Rc::make_mut(&mut self.components);
+        let defaults = components.remove(&TypeId::of::<T>()).unwrap_or_default();
+        components.insert(TypeId::of::<T>(), attributes.into().with_defaults(defaults));
+        self
+    }
+
+    /// Defaults for struct components of type `T`.
+    pub fn get<T: 'static>(&self) -> Attributes {
+        self.components
+            .get(&TypeId::of::<T>())
+            .cloned()
+            .unwrap_or_default()
+            .with_defaults(self.all.clone())
+    }
+
+    pub fn with_defaults(self, defaults: AttributeDefaults) -> AttributeDefaults {
+        let mut components = Rc::unwrap_or_clone(defaults.components);
+        for (type_id, attributes) in Rc::unwrap_or_clone(self.components) {
+            let defaults = components.remove(&type_id).unwrap_or_default();
+            components.insert(type_id, attributes.with_defaults(defaults));
+        }
+
+        AttributeDefaults {
+            all: self.all.with_defaults(defaults.all),
+            components: Rc::new(components),
+        }
+    }
+}
+
+#[derive(PartialEq, Properties)]
+pub struct AttributesProviderProps {
+    pub defaults: AttributeDefaults,
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Provide default attributes to struct components with `#[struct_component(provided_attributes)]`.
+///
+/// Nested providers take precedence over their ancestors.
+#[function_component]
+pub fn AttributesProvider(props: &AttributesProviderProps) -> Html {
+    let parent = use_context::<AttributeDefaults>();
+    let defaults = use_memo(
+        (parent, props.defaults.clone()),
+        |(parent, defaults)| match parent {
+            Some(parent) => defaults.clone().with_defaults(parent.clone()),
+            None => defaults.clone(),
+        },
+    );
+
+    html! {
+        <ContextProvider<AttributeDefaults> context={(*defaults).clone()}>
+            {props.children.clone()}
+        </ContextProvider<AttributeDefaults>>
+    }
+}
+
+/// Struct components with `#[struct_component(provided_attributes)]`.
+///
+/// Implemented by `#[derive(StructComponent)]`.
+pub trait ProvidedAttributes: Sized + 'static {
+    /// Merge `defaults` into the attributes at lowest precedence.
+    ///
+    /// Defaults for attributes with a dedicated field are ignored.
+    fn with_provided_attributes(self, defaults: Attributes) -> Self;
+}
+
+/// Merge default attributes from the nearest [`AttributesProvider`].
+#[hook]
+pub fn use_provided_attributes<T>(props: T) -> T
+where
+    T: ProvidedAttributes,
+{
+    let defaults = use_context::<AttributeDefaults>();
+
+    match defaults {
+        Some(defaults) => props.with_provided_attributes(defaults.get::<T>()),
+        None => props,
+    }
+}
@@ -0,0 +1,49 @@
+use web_sys::{Event, HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement, InputEvent};
+use yew::{Callback, TargetCast};
+
+/// Read the value of the form control that dispatched the event.
+fn event_value(event: &Event) -> Option<String> {
+    event
+        .target_dyn_into::<HtmlInputElement>()
+        .map(|input| input.value())
+        .or_else(|| {
+            event
+                .target_dyn_into::<HtmlTextAreaElement>()
+                .map(|text_area| text_area.value())
+        })
+        .or_else(|| {
+            event
+                .target_dyn_into::<HtmlSelectElement>()
+                .map(|select| select.value())
+        })
+}
+
+/// Listener for a `value` field with `#[struct_component(bind = callback)]`, which emits the new value to the callback.
+///
+/// The callback is only notified, the field keeps rendering its own value. Keep the value in sync by updating the field
+/// from the callback, e.g. with the setter returned by [`use_controllable_state`](crate::use_controllable_state).
+pub fn bind_value<T: From<String> + 'static>(
+    callback: impl Into<Option<Callback<T>>>,
+) -> Option<Callback<InputEvent>> {
+    callback.into().map(|callback| {
+        Callback::from(move |event: InputEvent| {
+            if let Some(value) = event_value(&event) {
+                callback.emit(T::from(value));
+            }
+        })
+    })
+}
+
+/// Listener for a `checked` field with `#[struct_component(bind = callback)]`, which emits the new state to the callback.
+///
+/// The callback is only notified, the field keeps rendering its own state. Keep the state in sync by updating the field
+/// from the callback, e.g. with the setter returned by [`use_controllable_state`](crate::use_controllable_state).
+pub fn bind_checked(callback: impl Into<Option<Callback<bool>>>) -> Option<Callback<Event>> {
+    callback.into().map(|callback| {
+        Callback::from(move |event: Event| {
+            if let Some(input) = event.target_dyn_into::<HtmlInputElement>() {
+                callback.emit(input.checked());
+            }
+        })
+    })
+}
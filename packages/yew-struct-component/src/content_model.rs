@@ -0,0 +1,243 @@
+use std::fmt::{self, Display};
+
+use yew::virtual_dom::{VNode, VTag};
+
+const PHRASING: &[&str] = &[
+    "a", "abbr", "area", "audio", "b", "bdi", "bdo", "br", "button", "canvas", "cite", "code",
+    "data", "datalist", "del", "dfn", "em", "embed", "i", "iframe", "img", "input", "ins", "kbd",
+    "label", "link", "map", "mark", "math", "meta", "meter", "noscript", "object", "output",
+    "picture", "progress", "q", "ruby", "s", "samp", "script", "select", "slot", "small", "span",
+    "strong", "sub", "sup", "svg", "template", "textarea", "time", "u", "var", "video", "wbr",
+];
+
+/// Elements which only allow phrasing content.
+const PHRASING_ONLY: &[&str] = &[
+    "abbr", "b", "bdi", "bdo", "button", "cite", "code", "data", "dfn", "em", "h1", "h2", "h3",
+    "h4", "h5", "h6", "i", "kbd", "label", "mark", "output", "p", "pre", "q", "s", "samp", "small",
+    "span", "strong", "sub", "sup", "time", "u", "var",
+];
+
+/// Elements whose content model is that of their parent.
+const TRANSPARENT: &[&str] = &[
+    "a", "audio", "canvas", "del", "ins", "map", "noscript", "object", "slot", "video",
+];
+
+/// Elements which must not contain interactive content.
+const NON_INTERACTIVE_CONTENT: &[&str] = &["a", "button"];
+
+/// Interactive elements, `<input>` is interactive unless its type is `hidden`.
+const INTERACTIVE: &[&str] = &[
+    "a", "button", "details", "embed", "iframe", "label", "select", "textarea",
+];
+
+/// Elements whose content is not HTML.
+const OPAQUE: &[&str] = &["math", "script", "style", "svg", "template"];
+
+const TABLE_CHILDREN: &[(&str, &[&str])] = &[
+    (
+        "table",
+        &[
+            "caption", "colgroup", "thead", "tbody", "tfoot", "script", "template",
+        ],
+    ),
+    ("thead", &["tr", "script", "template"]),
+    ("tbody", &["tr", "script", "template"]),
+    ("tfoot", &["tr", "script", "template"]),
+    ("tr", &["td", "th", "script", "template"]),
+    ("colgroup", &["col", "template"]),
+];
+
+const TABLE_PARENTS: &[(&str, &[&str])] = &[
+    ("caption", &["table"]),
+    ("colgroup", &["table"]),
+    ("thead", &["table"]),
+    ("tbody", &["table"]),
+    ("tfoot", &["table"]),
+    ("tr", &["thead", "tbody", "tfoot"]),
+    ("td", &["tr"]),
+    ("th", &["tr"]),
+    ("col", &["colgroup"]),
+];
+
+fn is_interactive(tag: &VTag) -> bool {
+    match tag.tag() {
+        "input" => !tag
+            .attributes
+            .iter()
+            .any(|(key, value)| key == "type" && value.eq_ignore_ascii_case("hidden")),
+        name => INTERACTIVE.contains(&name),
+    }
+}
+
+fn lookup<'a>(table: &[(&str, &'a [&'a str])], name: &str) -> Option<&'a [&'a str]> {
+    table
+        .iter()
+        .find(|(key, _)| *key == name)
+        .map(|(_, values)| *values)
+}
+
+/// Element nesting which browsers restructure when parsing HTML.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContentModelIssue {
+    /// Tag names from the checked root to the offending element, separated by ` > `.
+    pub path: String,
+    pub message: String,
+}
+
+impl Display for ContentModelIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct Context<'a> {
+    parent: Option<&'a str>,
+    phrasing_only: Option<&'a str>,
+    non_interactive: Option<&'a str>,
+}
+
+impl Context<'_> {
+    fn is_empty(&self) -> bool {
+        self.parent.is_none() && self.phrasing_only.is_none() && self.non_interactive.is_none()
+    }
+}
+
+struct Validator<'a> {
+    path: Vec<&'a str>,
+    issues: Vec<ContentModelIssue>,
+    /// Only check the rules imposed by the root tag, nested struct components check their own when they render.
+    root_only: bool,
+}
+
+impl<'a> Validator<'a> {
+    fn issue(&mut self, message: String) {
+        self.issues.push(ContentModelIssue {
+            path: self.path.join(" > "),
+            message,
+        });
+    }
+
+    fn visit_node(&mut self, node: &'a VNode, context: Context<'a>) {
+        match node {
+            VNode::VTag(tag) => self.visit_tag(tag, context),
+            VNode::VList(list) => {
+                for node in list.iter() {
+                    self.visit_node(node, context);
+                }
+            }
+            VNode::VText(text) => {
+                if let Some(parent) = context.parent
+                    && lookup(TABLE_CHILDREN, parent).is_some()
+                    && !text.text.trim().is_empty()
+                {
+                    self.issue(format!("text is not allowed in `<{parent}>`"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_tag(&mut self, tag: &'a VTag, context: Context<'a>) {
+        let name = tag.tag();
+        self.path.push(name);
+
+        if let Some(ancestor) = context.phrasing_only
+            && !PHRASING.contains(&name)
+        {
+            self.issue(format!(
+                "`<{name}>` is not phrasing content, but `<{ancestor}>` only allows phrasing content"
+            ));
+        }
+
+        if let Some(ancestor) = context.non_interactive
+            && is_interactive(tag)
+        {
+            self.issue(format!(
+                "interactive `<{name}>` is not allowed inside `<{ancestor}>`"
+            ));
+        }
+
+        if let Some(parent) = context.parent {
+            if let Some(children) = lookup(TABLE_CHILDREN, parent) {
+                if parent == "table" && name == "tr" {
+                    // The parser wraps the row in a `<tbody>`, which hydration doesn't expect.
+                    self.issue(
+                        "`<tr>` is not allowed in `<table>`, browsers insert a `<tbody>` around it"
+                            .to_string(),
+                    );
+                } else if !children.contains(&name) {
+                    self.issue(format!("`<{name}>` is not allowed in `<{parent}>`"));
+                }
+            } else if let Some(parents) = lookup(TABLE_PARENTS, name)
+                && !parents.contains(&parent)
+            {
+                self.issue(format!(
+                    "`<{name}>` must be a child of {}",
+                    parents
+                        .iter()
+                        .map(|parent| format!("`<{parent}>`"))
+                        .collect::<Vec<_>>()
+                        .join(" or ")
+                ));
+            }
+        }
+
+        if !OPAQUE.contains(&name)
+            && let Some(children) = tag.children()
+        {
+            // Rules imposed by this tag, which are left out below the root when only the root is checked.
+            let own = (!self.root_only || self.path.len() == 1).then_some(name);
+
+            let context = Context {
+                parent: own,
+                phrasing_only: if PHRASING_ONLY.contains(&name) {
+                    own
+                } else if TRANSPARENT.contains(&name) {
+                    context.phrasing_only
+                } else {
+                    None
+                },
+                non_interactive: if NON_INTERACTIVE_CONTENT.contains(&name) {
+                    own
+                } else {
+                    context.non_interactive
+                },
+            };
+
+            if !context.is_empty() {
+                self.visit_node(children, context);
+            }
+        }
+
+        self.path.pop();
+    }
+}
+
+/// Check a node tree for element nesting that violates the HTML content models.
+pub fn content_model_issues(node: &VNode) -> Vec<ContentModelIssue> {
+    let mut validator = Validator {
+        path: vec![],
+        issues: vec![],
+        root_only: false,
+    };
+    validator.visit_node(node, Context::default());
+    validator.issues
+}
+
+/// Log content model issues caused by the tag of a rendered struct component.
+///
+/// Each issue is reported once, by the struct component whose tag imposes the violated rule.
+#[cfg(debug_assertions)]
+pub(crate) fn report(tag: &VTag) {
+    let mut validator = Validator {
+        path: vec![],
+        issues: vec![],
+        root_only: true,
+    };
+    validator.visit_tag(tag, Context::default());
+
+    for issue in validator.issues {
+        log::warn!("{issue}");
+    }
+}
@@ -0,0 +1,55 @@
+use yew::prelude::*;
+
+/// Parameters of [`use_controllable_state`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseControllableStateParams<T> {
+    /// Controlled value, the state is uncontrolled if [`None`].
+    pub prop: Option<T>,
+    /// Initial value of the uncontrolled state.
+    pub default_prop: Option<T>,
+    /// Called with the next value whenever the value should change.
+    pub on_change: Option<Callback<T>>,
+}
+
+/// State which is controlled by a prop when it is set and kept internally otherwise.
+///
+/// Returns the current value and a callback to change it. Controlled state is only changed through `on_change`.
+#[hook]
+pub fn use_controllable_state<T>(params: UseControllableStateParams<T>) -> (Option<T>, Callback<T>)
+where
+    T: Clone + PartialEq + 'static,
+{
+    let UseControllableStateParams {
+        prop,
+        default_prop,
+        on_change,
+    } = params;
+
+    let uncontrolled = use_state_eq(|| default_prop);
+
+    let is_controlled = prop.is_some();
+    let value = if is_controlled {
+        prop
+    } else {
+        (*uncontrolled).clone()
+    };
+
+    let set_value = use_callback(
+        (is_controlled, value.clone(), on_change),
+        move |next: T, (is_controlled, value, on_change)| {
+            if value.as_ref() == Some(&next) {
+                return;
+            }
+
+            if !is_controlled {
+                uncontrolled.set(Some(next.clone()));
+            }
+
+            if let Some(on_change) = on_change {
+                on_change.emit(next);
+            }
+        },
+    );
+
+    (value, set_value)
+}
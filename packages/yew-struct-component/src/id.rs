@@ -0,0 +1,104 @@
+use std::{cell::Cell, rc::Rc};
+
+use yew::prelude::*;
+
+/// Counter shared by all ids generated below an [`IdProvider`].
+#[derive(Clone, Debug)]
+pub struct IdContext {
+    prefix: AttrValue,
+    counter: Rc<Cell<usize>>,
+}
+
+impl IdContext {
+    fn new(prefix: AttrValue) -> Self {
+        Self {
+            prefix,
+            counter: Rc::default(),
+        }
+    }
+
+    fn next(&self) -> AttrValue {
+        let id = self.counter.get();
+        self.counter.set(id + 1);
+        AttrValue::from(format!("{}-{id}", self.prefix))
+    }
+}
+
+impl PartialEq for IdContext {
+    fn eq(&self, other: &Self) -> bool {
+        self.prefix == other.prefix && Rc::ptr_eq(&self.counter, &other.counter)
+    }
+}
+
+#[derive(PartialEq, Properties)]
+pub struct IdProviderProps {
+    /// Prefix of the generated ids, defaults to `yew-id`.
+    ///
+    /// A provider with a prefix starts a new scope. Apps which are rendered separately on the same page need different
+    /// prefixes.
+    #[prop_or_default]
+    pub prefix: Option<AttrValue>,
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Scope for generated ids.
+///
+/// Components are created in the same order during server-side rendering and hydration, so ids generated below a
+/// provider match on both sides. Nested providers without a `prefix` continue the counter of their closest ancestor
+/// provider, so all ids within one app are unique.
+#[function_component]
+pub fn IdProvider(props: &IdProviderProps) -> Html {
+    let parent = use_context::<IdContext>();
+    let prefix = props.prefix.clone();
+    let context = use_state(move || match (prefix, parent) {
+        (None, Some(parent)) => parent,
+        (prefix, _) => IdContext::new(prefix.unwrap_or(AttrValue::Static("yew-id"))),
+    });
+
+    html! {
+        <ContextProvider<IdContext> context={(*context).clone()}>
+            {props.children.clone()}
+        </ContextProvider<IdContext>>
+    }
+}
+
+/// Generate an id which stays the same for the lifetime of the component.
+///
+/// Returns `deterministic_id` if it is set.
+///
+/// # Panics
+///
+/// Panics without an [`IdProvider`] ancestor, as ids generated outside a provider would not match after hydration.
+#[hook]
+pub fn use_id(deterministic_id: Option<AttrValue>) -> AttrValue {
+    let context = use_context::<IdContext>();
+    let id = use_state(move || {
+        context
+            .expect("`use_id` requires an `IdProvider` ancestor to generate ids which match after hydration")
+            .next()
+    });
+
+    deterministic_id.unwrap_or_else(|| (*id).clone())
+}
+
+/// Struct components with an `#[struct_component(auto_id)]` field.
+///
+/// Implemented by `#[derive(StructComponent)]`.
+pub trait AutoId: Sized {
+    /// Set the id field to `id` if it is unset.
+    fn with_auto_id(self, id: AttrValue) -> Self;
+}
+
+/// Fill the `#[struct_component(auto_id)]` field with a generated id if it is unset.
+///
+/// The id can be referenced by sibling components through the returned props.
+#[hook]
+pub fn use_auto_id<T>(props: T) -> T
+where
+    T: AutoId,
+{
+    let id = use_id(None);
+
+    props.with_auto_id(id)
+}
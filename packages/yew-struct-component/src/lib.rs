@@ -1,6 +1,81 @@
 //! Define [Yew](https://yew.rs/) components using structs.
+#[cfg(feature = "a11y")]
+mod a11y;
+mod aria;
 mod attributes;
+mod attributes_provider;
+mod bind;
+#[cfg(feature = "validate")]
+mod content_model;
+mod controllable_state;
+mod id;
+mod meta;
+mod safe_url;
+mod sanitize;
+#[cfg(feature = "tracing")]
+mod trace;
 
 pub use yew_struct_component_macro::*;
 
+#[cfg(feature = "a11y")]
+pub use crate::a11y::*;
+pub use crate::aria::*;
 pub use crate::attributes::*;
+pub use crate::attributes_provider::*;
+#[cfg(feature = "validate")]
+pub use crate::content_model::*;
+pub use crate::controllable_state::*;
+pub use crate::id::*;
+pub use crate::meta::*;
+pub use crate::safe_url::*;
+pub use crate::sanitize::*;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use yew;
+
+    pub use crate::bind::{bind_checked, bind_value};
+
+    /// Remove an attribute value which is not allowed by a sanitization policy.
+    pub fn sanitize(
+        policy: &crate::Policy,
+        tag: &str,
+        name: &str,
+        value: Option<yew::virtual_dom::AttributeOrProperty>,
+    ) -> Option<yew::virtual_dom::AttributeOrProperty> {
+        value.filter(|value| match value {
+            yew::virtual_dom::AttributeOrProperty::Attribute(value) => {
+                policy.allows(Some(tag), name, Some(value))
+            }
+            _ => policy.allows(Some(tag), name, None),
+        })
+    }
+
+    /// Enter a span for rendering a struct component with the `tracing` feature, does nothing otherwise.
+    #[inline(always)]
+    pub fn enter_render(_component: &'static str) -> impl Sized {
+        #[cfg(feature = "tracing")]
+        {
+            crate::trace::enter(_component)
+        }
+    }
+
+    /// Report a rendered struct component through the `tracing` feature.
+    #[inline(always)]
+    pub fn rendered(_tag: &yew::virtual_dom::VTag, _listeners: usize) {
+        #[cfg(feature = "tracing")]
+        crate::trace::rendered(_tag, _listeners);
+    }
+
+    /// Check a struct component rendered to HTML through the `a11y` and `validate` features.
+    ///
+    /// Only `render` and `render_ref` check, as callers of `into_vtag` and `to_vtag` may still complete the tag. The
+    /// checks only run in debug builds.
+    #[inline(always)]
+    pub fn check(_tag: &yew::virtual_dom::VTag) {
+        #[cfg(all(feature = "a11y", debug_assertions))]
+        crate::a11y::report(_tag);
+        #[cfg(all(feature = "validate", debug_assertions))]
+        crate::content_model::report(_tag);
+    }
+}
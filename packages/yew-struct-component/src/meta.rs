@@ -0,0 +1,57 @@
+/// How a struct component field is rendered.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AttributeKind {
+    /// Rendered as an attribute.
+    Attribute,
+    /// Applied as a property (`value`, `checked`).
+    ///
+    /// `value` on `<input>` and `<textarea>` and `checked` on `<input>` are only applied as a property, on other tags
+    /// they are also rendered as an attribute.
+    Property,
+    /// Attached as an event listener.
+    Listener,
+    /// Assigned as the node reference.
+    NodeRef,
+    /// Determines the tag name.
+    DynamicTag,
+    /// Additional attributes from an [`Attributes`](crate::Attributes) map.
+    Attributes,
+    /// Rendered as content of the element.
+    Content,
+}
+
+/// Description of a single struct component field.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct AttributeMeta {
+    /// Rust field name.
+    pub field: &'static str,
+    /// DOM name of the attribute or listener, empty for other kinds.
+    pub name: &'static str,
+    /// How the field is rendered.
+    pub kind: AttributeKind,
+    /// Whether the field can be omitted from the rendered output.
+    pub optional: bool,
+}
+
+/// Static description of what a struct component renders.
+///
+/// Implemented by `#[derive(StructComponent)]`.
+pub trait StructComponentMeta {
+    /// Static tag, or [`None`] if the tag is dynamic.
+    const TAG: Option<&'static str>;
+
+    /// Fields in declaration order.
+    const ATTRIBUTES: &'static [AttributeMeta];
+
+    fn attribute(name: &str) -> Option<&'static AttributeMeta> {
+        Self::ATTRIBUTES
+            .iter()
+            .find(|attribute| attribute.name == name)
+    }
+
+    fn listeners() -> impl Iterator<Item = &'static AttributeMeta> {
+        Self::ATTRIBUTES
+            .iter()
+            .filter(|attribute| attribute.kind == AttributeKind::Listener)
+    }
+}
@@ -0,0 +1,195 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::Deref,
+    str::FromStr,
+};
+
+use yew::{AttrValue, html::IntoPropValue};
+
+use crate::{
+    attributes::IntoAttributeValue,
+    sanitize::{Policy, url_scheme},
+};
+
+/// Error when parsing a [`SafeUrl`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SafeUrlError {
+    /// The URL scheme is not in the allow-list of the policy.
+    DisallowedScheme(String),
+    /// The URL is relative, which the policy does not allow.
+    Relative,
+}
+
+impl Display for SafeUrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DisallowedScheme(scheme) => write!(f, "URL scheme `{scheme}` is not allowed"),
+            Self::Relative => write!(f, "relative URLs are not allowed"),
+        }
+    }
+}
+
+impl Error for SafeUrlError {}
+
+/// A URL validated against the URL rules of a [`Policy`], so it is safe to render in URL attributes.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SafeUrl(AttrValue);
+
+impl SafeUrl {
+    /// Parses a URL with the rules of [`Policy::DEFAULT`].
+    pub fn parse(url: &str) -> Result<SafeUrl, SafeUrlError> {
+        SafeUrl::parse_with(url, &Policy::DEFAULT)
+    }
+
+    /// Parses a URL with the URL rules of `policy`.
+    ///
+    /// The URL is normalized like browsers do: surrounding whitespace, tabs and newlines are removed, the scheme is
+    /// lowercased and other characters which are not allowed in a URL are percent-encoded.
+    pub fn parse_with(url: &str, policy: &Policy) -> Result<SafeUrl, SafeUrlError> {
+        let url = normalize(url);
+
+        match url_scheme(&url) {
+            Some(scheme) if !policy.allows_url(&url) => Err(SafeUrlError::DisallowedScheme(scheme)),
+            None if !policy.relative_urls => Err(SafeUrlError::Relative),
+            Some(scheme) => Ok(SafeUrl(AttrValue::from(format!(
+                "{scheme}{}",
+                &url[scheme.len()..]
+            )))),
+            None => Ok(SafeUrl(AttrValue::from(url))),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+fn normalize(url: &str) -> String {
+    let mut normalized = String::with_capacity(url.len());
+    let mut characters = url
+        .trim_matches(|character: char| character <= ' ')
+        .chars()
+        .filter(|character| !matches!(character, '\t' | '\n' | '\r'))
+        .peekable();
+
+    while let Some(character) = characters.next() {
+        match character {
+            '%' => {
+                let mut lookahead = characters.clone();
+                match (lookahead.next(), lookahead.next()) {
+                    (Some(a), Some(b)) if a.is_ascii_hexdigit() && b.is_ascii_hexdigit() => {
+                        normalized.push('%');
+                        normalized.push(a.to_ascii_uppercase());
+                        normalized.push(b.to_ascii_uppercase());
+                        characters = lookahead;
+                    }
+                    _ => normalized.push_str("%25"),
+                }
+            }
+            character if character.is_ascii_graphic() => normalized.push(character),
+            character => {
+                for byte in character.encode_utf8(&mut [0; 4]).bytes() {
+                    normalized.push_str(&format!("%{byte:02X}"));
+                }
+            }
+        }
+    }
+
+    normalized
+}
+
+impl Deref for SafeUrl {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for SafeUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for SafeUrl {
+    type Err = SafeUrlError;
+
+    fn from_str(url: &str) -> Result<Self, Self::Err> {
+        SafeUrl::parse(url)
+    }
+}
+
+impl TryFrom<&str> for SafeUrl {
+    type Error = SafeUrlError;
+
+    fn try_from(url: &str) -> Result<Self, Self::Error> {
+        SafeUrl::parse(url)
+    }
+}
+
+impl TryFrom<String> for SafeUrl {
+    type Error = SafeUrlError;
+
+    fn try_from(url: String) -> Result<Self, Self::Error> {
+        SafeUrl::parse(&url)
+    }
+}
+
+impl From<SafeUrl> for AttrValue {
+    fn from(value: SafeUrl) -> AttrValue {
+        value.0
+    }
+}
+
+impl From<&SafeUrl> for AttrValue {
+    fn from(value: &SafeUrl) -> AttrValue {
+        value.0.clone()
+    }
+}
+
+impl IntoAttributeValue for SafeUrl {
+    fn into_attribute_value(self) -> Option<AttrValue> {
+        Some(self.0)
+    }
+}
+
+impl IntoAttributeValue for &SafeUrl {
+    fn into_attribute_value(self) -> Option<AttrValue> {
+        Some(self.0.clone())
+    }
+}
+
+impl IntoPropValue<Option<AttrValue>> for SafeUrl {
+    fn into_prop_value(self) -> Option<AttrValue> {
+        Some(self.0)
+    }
+}
+
+impl IntoPropValue<AttrValue> for SafeUrl {
+    fn into_prop_value(self) -> AttrValue {
+        self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+    use super::SafeUrl;
+
+    impl Serialize for SafeUrl {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    /// Deserialized with the rules of [`Policy::DEFAULT`][crate::Policy::DEFAULT].
+    impl<'de> Deserialize<'de> for SafeUrl {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let url = String::deserialize(deserializer)?;
+            SafeUrl::parse(&url).map_err(D::Error::custom)
+        }
+    }
+}
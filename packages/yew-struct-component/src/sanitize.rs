@@ -0,0 +1,242 @@
+/// Rules for removing unsafe attributes from untrusted [`Attributes`][crate::Attributes].
+///
+/// [`Policy::DEFAULT`] limits the attributes of tags which load or submit content, like `<a>`, `<img>`, `<iframe>` and
+/// `<form>`, to an allow-list. It removes the URLs of `<base>`, `<object>` and `<embed>`, `http-equiv`, which can
+/// redirect through `<meta>`, and `attributeName`, which lets SVG `<animate>` and `<set>` rewrite other attributes. Custom policies can extend the default policy, e.g. `Policy { url_schemes: &["https"], ..Policy::DEFAULT }`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Policy {
+    /// URL schemes allowed in URL attributes.
+    pub url_schemes: &'static [&'static str],
+    /// Whether relative URLs are allowed in URL attributes.
+    pub relative_urls: bool,
+    /// Attributes containing one or more URLs.
+    pub url_attributes: &'static [&'static str],
+    /// Whether event handler attributes like `onclick` are removed.
+    pub strip_event_handlers: bool,
+    /// Attributes which are always removed.
+    pub denied_attributes: &'static [&'static str],
+    /// Attributes allowed per tag, `"*"` lists attributes allowed on every listed tag.
+    ///
+    /// Tags without an entry allow any attribute. `data-*` and `aria-*` attributes are always allowed.
+    pub allowed_attributes: &'static [(&'static str, &'static [&'static str])],
+}
+
+impl Policy {
+    pub const DEFAULT: Policy = Policy {
+        url_schemes: &["http", "https", "mailto", "tel"],
+        relative_urls: true,
+        url_attributes: &[
+            "action",
+            "background",
+            "cite",
+            "data",
+            "formaction",
+            "href",
+            "imagesrcset",
+            "longdesc",
+            "manifest",
+            "ping",
+            "poster",
+            "src",
+            "srcset",
+            "xlink:href",
+        ],
+        strip_event_handlers: true,
+        denied_attributes: &["attributename", "http-equiv", "srcdoc"],
+        allowed_attributes: &[
+            (
+                "*",
+                &[
+                    "accesskey",
+                    "autocapitalize",
+                    "autofocus",
+                    "class",
+                    "contenteditable",
+                    "dir",
+                    "draggable",
+                    "enterkeyhint",
+                    "hidden",
+                    "id",
+                    "inert",
+                    "inputmode",
+                    "lang",
+                    "popover",
+                    "role",
+                    "slot",
+                    "spellcheck",
+                    "style",
+                    "tabindex",
+                    "title",
+                    "translate",
+                ],
+            ),
+            (
+                "a",
+                &[
+                    "download",
+                    "href",
+                    "hreflang",
+                    "ping",
+                    "referrerpolicy",
+                    "rel",
+                    "target",
+                    "type",
+                ],
+            ),
+            ("base", &[]),
+            ("embed", &["height", "type", "width"]),
+            (
+                "form",
+                &[
+                    "accept-charset",
+                    "action",
+                    "autocomplete",
+                    "enctype",
+                    "method",
+                    "name",
+                    "novalidate",
+                    "rel",
+                    "target",
+                ],
+            ),
+            (
+                "iframe",
+                &[
+                    "allow",
+                    "allowfullscreen",
+                    "height",
+                    "loading",
+                    "name",
+                    "referrerpolicy",
+                    "sandbox",
+                    "src",
+                    "width",
+                ],
+            ),
+            (
+                "img",
+                &[
+                    "alt",
+                    "crossorigin",
+                    "decoding",
+                    "fetchpriority",
+                    "height",
+                    "loading",
+                    "referrerpolicy",
+                    "sizes",
+                    "src",
+                    "srcset",
+                    "width",
+                ],
+            ),
+            (
+                "link",
+                &[
+                    "as",
+                    "crossorigin",
+                    "href",
+                    "hreflang",
+                    "imagesizes",
+                    "imagesrcset",
+                    "media",
+                    "referrerpolicy",
+                    "rel",
+                    "sizes",
+                    "type",
+                ],
+            ),
+            ("object", &["height", "name", "type", "width"]),
+        ],
+    };
+
+    /// Whether an attribute may be rendered, on `tag` if it is known.
+    pub fn allows(&self, tag: Option<&str>, name: &str, value: Option<&str>) -> bool {
+        let contains = |names: &[&str]| names.iter().any(|n| n.eq_ignore_ascii_case(name));
+
+        if self.strip_event_handlers
+            && name
+                .get(..2)
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case("on"))
+        {
+            return false;
+        }
+
+        if contains(self.denied_attributes) {
+            return false;
+        }
+
+        if let Some(tag) = tag
+            && let Some((_, allowed)) = self
+                .allowed_attributes
+                .iter()
+                .find(|(allowed_tag, _)| allowed_tag.eq_ignore_ascii_case(tag))
+        {
+            let global = self
+                .allowed_attributes
+                .iter()
+                .filter(|(allowed_tag, _)| *allowed_tag == "*")
+                .any(|(_, allowed)| contains(allowed));
+            let prefixed = ["data-", "aria-"].iter().any(|prefix| {
+                name.get(..prefix.len())
+                    .is_some_and(|name| name.eq_ignore_ascii_case(prefix))
+            });
+
+            if !contains(allowed) && !global && !prefixed {
+                return false;
+            }
+        }
+
+        match value {
+            Some(value) if contains(self.url_attributes) => {
+                urls(name, value).all(|url| self.allows_url(url))
+            }
+            _ => true,
+        }
+    }
+
+    /// Whether a URL uses one of the allowed schemes, or is relative if those are allowed.
+    pub fn allows_url(&self, url: &str) -> bool {
+        match url_scheme(url) {
+            Some(scheme) => self
+                .url_schemes
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(&scheme)),
+            None => self.relative_urls,
+        }
+    }
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Policy::DEFAULT
+    }
+}
+
+/// Split the URLs in attributes which contain a list of them.
+fn urls<'a>(name: &str, value: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+    if name.eq_ignore_ascii_case("srcset") || name.eq_ignore_ascii_case("imagesrcset") {
+        Box::new(
+            value
+                .split(',')
+                .filter_map(|candidate| candidate.split_ascii_whitespace().next()),
+        )
+    } else if name.eq_ignore_ascii_case("ping") {
+        Box::new(value.split_ascii_whitespace())
+    } else {
+        Box::new([value].into_iter())
+    }
+}
+
+pub(crate) fn url_scheme(url: &str) -> Option<String> {
+    // Browsers ignore leading control characters and spaces, and tabs and newlines anywhere in a URL.
+    let url = url
+        .trim_start_matches(|character: char| character <= ' ')
+        .chars()
+        .filter(|character| !matches!(character, '\t' | '\n' | '\r'))
+        .collect::<String>();
+
+    let end = url.find([':', '/', '?', '#'])?;
+    url[end..]
+        .starts_with(':')
+        .then(|| url[..end].to_ascii_lowercase())
+}
@@ -0,0 +1,22 @@
+use tracing::{Level, debug, debug_span, enabled, span::EnteredSpan, trace};
+use yew::virtual_dom::VTag;
+
+pub fn enter(component: &'static str) -> EnteredSpan {
+    debug_span!("render", component).entered()
+}
+
+pub fn rendered(tag: &VTag, listeners: usize) {
+    debug!(
+        tag = tag.tag(),
+        attributes = tag.attributes.iter().count(),
+        listeners,
+        "rendered"
+    );
+
+    if enabled!(Level::TRACE) {
+        trace!(
+            attributes = ?tag.attributes.iter().collect::<Vec<_>>(),
+            "resolved attributes"
+        );
+    }
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "a11y")]
+
+use yew::prelude::*;
+use yew_struct_component::{A11yIssue, StructComponent, a11y_issues, a11y_reference_issues};
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "img", no_children = true)]
+struct ImageChildProps {
+    pub alt: Option<String>,
+    pub src: String,
+}
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "button")]
+struct ButtonChildProps {
+    pub role: Option<String>,
+    pub aria_label: Option<String>,
+    pub aria_expanded: Option<String>,
+    pub aria_labeledby: Option<String>,
+}
+
+fn issue(tag: &str, message: &str) -> A11yIssue {
+    A11yIssue {
+        tag: tag.to_string(),
+        message: message.to_string(),
+    }
+}
+
+#[test]
+fn test_image() {
+    let image = ImageChildProps {
+        alt: None,
+        src: "image.png".to_string(),
+    };
+    assert_eq!(
+        vec![issue("img", "missing `alt` attribute")],
+        a11y_issues(&image.into_vtag())
+    );
+
+    let image = ImageChildProps {
+        alt: Some(String::new()),
+        src: "image.png".to_string(),
+    };
+    assert_eq!(Vec::<A11yIssue>::new(), a11y_issues(&image.into_vtag()));
+}
+
+#[test]
+fn test_button() {
+    let button = ButtonChildProps {
+        role: Some("buton".to_string()),
+        aria_label: None,
+        aria_expanded: Some("yes".to_string()),
+        aria_labeledby: Some("label".to_string()),
+    };
+    assert_eq!(
+        vec![
+            issue("button", "unknown role `buton`"),
+            issue("button", "invalid value `yes` for `aria-expanded`"),
+            issue("button", "unknown attribute `aria-labeledby`"),
+            issue("button", "missing accessible name"),
+        ],
+        a11y_issues(&button.into_vtag(Html::default()))
+    );
+
+    let button = ButtonChildProps {
+        role: None,
+        aria_label: None,
+        aria_expanded: Some("false".to_string()),
+        aria_labeledby: None,
+    };
+    assert_eq!(
+        Vec::<A11yIssue>::new(),
+        a11y_issues(&button.clone().into_vtag(html! { <>{"Open"}</> }))
+    );
+    assert_eq!(
+        Vec::<A11yIssue>::new(),
+        a11y_issues(&button.into_vtag(html! { <img alt="Open" /> }))
+    );
+}
+
+#[test]
+fn test_references() {
+    assert_eq!(
+        vec![issue(
+            "div",
+            "`aria-describedby` references missing id `description`"
+        )],
+        a11y_reference_issues(concat!(
+            r#"<div role="dialog" aria-labelledby="title" aria-describedby="description">"#,
+            r#"<h2 id="title">Title</h2>"#,
+            r#"</div>"#,
+        ))
+    );
+}
@@ -0,0 +1,104 @@
+use yew::{AttrValue, ServerRenderer, prelude::*};
+use yew_struct_component::{
+    Aria, AriaCurrent, AriaHasPopup, AriaRole, AttributeKind, Attributes, IdRefs, StructComponent,
+    StructComponentMeta, Tristate,
+};
+
+#[test]
+fn test_into_attributes() {
+    assert_eq!(Attributes::default(), Attributes::from(Aria::default()));
+
+    assert_eq!(
+        Attributes::from([
+            ("role", "menuitemcheckbox"),
+            ("aria-labelledby", "menu-label"),
+            ("aria-describedby", "hint error"),
+            ("aria-checked", "mixed"),
+            ("aria-haspopup", "menu"),
+            ("aria-current", "page"),
+            ("aria-expanded", "false"),
+            ("aria-disabled", "true"),
+        ]),
+        Attributes::from(Aria {
+            role: Some(AriaRole::MenuItemCheckbox),
+            labelledby: IdRefs::from(["menu-label"]),
+            describedby: ["hint", "error"].into_iter().collect(),
+            checked: Some(Tristate::Mixed),
+            haspopup: Some(AriaHasPopup::Menu),
+            current: Some(AriaCurrent::Page),
+            expanded: Some(false),
+            disabled: Some(true),
+            ..Aria::default()
+        })
+    );
+
+    assert_eq!(
+        AttrValue::from("true"),
+        AttrValue::from(Tristate::from(true))
+    );
+    assert_eq!(
+        AttrValue::from("alertdialog"),
+        AttrValue::from(AriaRole::AlertDialog)
+    );
+}
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "button")]
+struct ToggleChildProps {
+    pub title: Option<String>,
+    #[struct_component(flatten)]
+    pub aria: Aria,
+    pub attributes: Attributes,
+}
+
+#[derive(PartialEq, Properties)]
+struct ToggleProps {
+    pub pressed: bool,
+    #[prop_or_default]
+    pub attributes: Attributes,
+}
+
+#[function_component]
+fn Toggle(props: &ToggleProps) -> Html {
+    ToggleChildProps {
+        title: Some("Bold".to_owned()),
+        aria: Aria {
+            pressed: Some(props.pressed.into()),
+            controls: IdRefs::from(["editor"]),
+            ..Aria::default()
+        },
+        attributes: props.attributes.clone(),
+    }
+    .render(html! {})
+}
+
+#[function_component]
+fn App() -> Html {
+    html! {
+        <>
+            <Toggle pressed=true />
+            <Toggle pressed=false attributes={[("aria-pressed", "mixed")]} />
+        </>
+    }
+}
+
+#[tokio::test]
+async fn test_flatten() {
+    let renderer = ServerRenderer::<App>::new().hydratable(false);
+    let rendered = renderer.render().await;
+    let (first, second) = rendered.split_once("</button>").expect("two buttons");
+
+    assert!(first.contains(r#"title="Bold""#));
+    assert!(first.contains(r#"aria-pressed="true""#));
+    assert!(first.contains(r#"aria-controls="editor""#));
+
+    // User attributes take precedence over the flattened field.
+    assert!(second.contains(r#"aria-pressed="mixed""#));
+    assert!(!second.contains(r#"aria-pressed="false""#));
+
+    assert!(
+        ToggleChildProps::ATTRIBUTES
+            .iter()
+            .any(|meta| meta.field == "aria" && meta.kind == AttributeKind::Attributes)
+    );
+}
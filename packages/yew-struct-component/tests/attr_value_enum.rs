@@ -0,0 +1,107 @@
+use yew::{ServerRenderer, html::IntoPropValue, prelude::*};
+use yew_struct_component::{AttrValueEnum, Attributes, StructComponent, attributes};
+
+#[derive(AttrValueEnum, Clone, Copy, Debug, Eq, PartialEq)]
+#[attr_value(rename_all = "kebab-case")]
+enum State {
+    Open,
+    Closed,
+    #[attr_value(rename = "half")]
+    HalfOpen,
+}
+
+#[derive(AttrValueEnum, Clone, Copy, Debug, Eq, PartialEq)]
+enum Orientation {
+    #[allow(unused)]
+    Horizontal,
+    Vertical,
+}
+
+#[derive(AttrValueEnum, Clone, Copy, Debug, Eq, PartialEq)]
+#[attr_value(rename_all = "SCREAMING_SNAKE_CASE")]
+enum Casing {
+    SomeValue,
+}
+
+#[test]
+fn test_as_str() {
+    assert_eq!("open", State::Open.as_str());
+    assert_eq!("closed", State::Closed.to_string());
+    assert_eq!("half", State::HalfOpen.as_str());
+    assert_eq!("Vertical", Orientation::Vertical.as_str());
+    assert_eq!("SOME_VALUE", Casing::SomeValue.as_str());
+
+    assert_eq!(&[State::Open, State::Closed, State::HalfOpen], State::ALL);
+    assert_eq!(&["open", "closed", "half"], State::VALUES);
+}
+
+#[test]
+fn test_conversions() {
+    assert!(matches!(
+        AttrValue::from(State::Open),
+        AttrValue::Static("open")
+    ));
+    assert!(matches!(
+        AttrValue::from(&State::Closed),
+        AttrValue::Static("closed")
+    ));
+
+    let value: AttrValue = State::Open.into_prop_value();
+    assert_eq!(AttrValue::Static("open"), value);
+
+    let value: Option<AttrValue> = State::Closed.into_prop_value();
+    assert_eq!(Some(AttrValue::Static("closed")), value);
+}
+
+#[test]
+fn test_attributes() {
+    let state = State::HalfOpen;
+    let mut attributes = Attributes::from([("data-state", State::Open)]);
+    attributes.insert("data-orientation", Some(Orientation::Vertical));
+
+    assert_eq!(
+        Attributes::from([("data-state", "open"), ("data-orientation", "Vertical")]),
+        attributes
+    );
+    assert_eq!(
+        Attributes::from([("data-state", "half")]),
+        attributes! { "data-state" => state, "data-orientation" => ?None::<Orientation> }
+    );
+    assert_eq!(
+        Attributes::from([("data-state", "half")]),
+        attributes! { "data-state" => &state }
+    );
+}
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "div")]
+struct CollapsibleChildProps {
+    pub attributes: Attributes,
+
+    pub data_state: State,
+    pub data_orientation: Option<Orientation>,
+}
+
+#[function_component]
+fn App() -> Html {
+    CollapsibleChildProps {
+        attributes: Attributes::default(),
+
+        data_state: State::Open,
+        data_orientation: Some(Orientation::Vertical),
+    }
+    .render(html! {
+        <span data-state={State::Closed} />
+    })
+}
+
+#[tokio::test]
+async fn test_render() {
+    let renderer = ServerRenderer::<App>::new().hydratable(false);
+    let html = renderer.render().await;
+
+    assert_eq!(
+        r#"<div data-state="open" data-orientation="Vertical"><span data-state="closed"></span></div>"#,
+        html
+    );
+}
@@ -0,0 +1,385 @@
+use std::collections::{BTreeMap, HashMap};
+
+use yew::{AttrValue, ServerRenderer, prelude::*};
+use yew_struct_component::{AttributePairs, Attributes, Merge, StructComponent, attributes};
+
+#[test]
+fn test_map() {
+    let mut attributes = Attributes::default();
+
+    assert!(attributes.is_empty());
+    assert_eq!(None, attributes.get("title"));
+
+    assert_eq!(None, attributes.insert("title", AttrValue::Static("Title")));
+    assert_eq!(None, attributes.insert("hidden", None::<AttrValue>));
+    assert_eq!(
+        Some(Some(AttrValue::Static("Title"))),
+        attributes.insert("title", AttrValue::Static("Other"))
+    );
+
+    assert_eq!(2, attributes.len());
+    assert!(attributes.contains("hidden"));
+    assert_eq!(Some(&None), attributes.get("hidden"));
+    assert_eq!(
+        Some(&Some(AttrValue::Static("Other"))),
+        attributes.get("title")
+    );
+
+    assert_eq!(Some(None), attributes.remove("hidden"));
+    assert_eq!(None, attributes.remove("hidden"));
+
+    attributes.retain(|key, _| key != "title");
+
+    // Removing every attribute is the same as having none.
+    assert!(attributes.is_empty());
+    assert_eq!(Attributes::default(), attributes);
+}
+
+#[test]
+fn test_iter() {
+    let mut attributes = Attributes::from([("rel", "noopener"), ("target", "_blank")]);
+
+    for (_, value) in &mut attributes {
+        *value = value
+            .as_ref()
+            .map(|value| AttrValue::from(value.to_uppercase()));
+    }
+
+    let mut pairs = attributes
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.as_ref().map(ToString::to_string)))
+        .collect::<Vec<_>>();
+    pairs.sort();
+
+    assert_eq!(
+        vec![
+            ("rel".to_string(), Some("NOOPENER".to_string())),
+            ("target".to_string(), Some("_BLANK".to_string())),
+        ],
+        pairs
+    );
+
+    let filtered = attributes
+        .into_iter()
+        .filter(|(key, _)| key != "target")
+        .collect::<Attributes>();
+
+    assert_eq!(Attributes::from([("rel", "NOOPENER")]), filtered);
+    assert_eq!(
+        Attributes::default(),
+        Vec::<(AttrValue, AttrValue)>::new()
+            .into_iter()
+            .collect::<Attributes>()
+    );
+}
+
+#[test]
+fn test_merge() {
+    let attributes = Attributes::from([("dir", "rtl"), ("lang", "nl")]);
+
+    assert_eq!(
+        Attributes::from([("dir", "rtl"), ("lang", "nl"), ("title", "Title")]),
+        attributes
+            .clone()
+            .with_defaults([("dir", "ltr"), ("title", "Title")])
+    );
+    assert_eq!(
+        Attributes::from([("dir", "ltr"), ("lang", "nl"), ("title", "Title")]),
+        attributes
+            .clone()
+            .with_overrides([("dir", "ltr"), ("title", "Title")])
+    );
+
+    let mut merged = Attributes::default();
+    merged.merge(attributes.clone());
+    merged.extend([(AttrValue::Static("lang"), AttrValue::Static("en"))]);
+
+    assert_eq!(Attributes::from([("dir", "rtl"), ("lang", "en")]), merged);
+    assert_eq!(
+        Attributes::from([("dir", "rtl"), ("lang", "nl")]),
+        attributes
+    );
+}
+
+#[test]
+fn test_conversions() {
+    let expected = Attributes::from([("data-index", "1"), ("hidden", "")]);
+
+    assert_eq!(
+        expected,
+        Attributes::from(vec![
+            ("data-index", AttrValue::Static("1")),
+            ("hidden", AttrValue::Static("")),
+        ])
+    );
+    assert_eq!(
+        expected,
+        Attributes::from([("data-index", Some(1)), ("hidden", None)])
+            .with_overrides([("hidden", true)])
+    );
+    assert_eq!(
+        Some(&None),
+        Attributes::from([("title", false)]).get("title")
+    );
+    assert_eq!(
+        expected,
+        Attributes::from(BTreeMap::from([
+            ("data-index".to_string(), "1".to_string()),
+            ("hidden".to_string(), String::new()),
+        ]))
+    );
+    assert_eq!(
+        expected,
+        Attributes::from(HashMap::from([("data-index", 1.0), ("hidden", 0.0)]))
+            .with_overrides([("hidden", true)])
+    );
+    assert_eq!(Attributes::default(), Attributes::from(None));
+    assert_eq!(expected.clone(), Attributes::from(Some(expected.clone())));
+    assert_eq!(
+        expected,
+        Attributes::from(AttributePairs(
+            ["data-index", "hidden"]
+                .into_iter()
+                .zip([Some(AttrValue::Static("1")), Some(AttrValue::Static(""))])
+        ))
+    );
+}
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "div")]
+struct BoxChildProps {
+    pub attributes: Attributes,
+}
+
+#[derive(PartialEq, Properties)]
+struct BoxProps {
+    #[prop_or_default]
+    pub attributes: Attributes,
+}
+
+#[function_component]
+fn Box(props: &BoxProps) -> Html {
+    BoxChildProps {
+        attributes: props.attributes.clone(),
+    }
+    .render(html! {})
+}
+
+#[function_component]
+fn App() -> Html {
+    let index = 2;
+    let states = ["open", "checked"];
+
+    html! {
+        <>
+            <Box attributes={vec![("data-index", 1)]} />
+            <Box attributes={[("data-index", index.to_string())]} />
+            <Box attributes={BTreeMap::from([("hidden", true)])} />
+            <Box attributes={None} />
+            <Box attributes={AttributePairs(states.iter().map(|state| (format!("data-{state}"), true)).take(1))} />
+        </>
+    }
+}
+
+#[tokio::test]
+async fn test_prop_values() {
+    let renderer = ServerRenderer::<App>::new().hydratable(false);
+
+    assert_eq!(
+        concat!(
+            r#"<div data-index="1"></div>"#,
+            r#"<div data-index="2"></div>"#,
+            r#"<div hidden=""></div>"#,
+            r#"<div></div>"#,
+            r#"<div data-open=""></div>"#,
+        ),
+        renderer.render().await
+    );
+}
+
+#[test]
+fn test_macro() {
+    let label = "Close";
+    let maybe_state: Option<AttrValue> = None;
+    let open = true;
+
+    assert_eq!(
+        Attributes::from([
+            ("aria-label", "Close"),
+            ("disabled", ""),
+            ("data-open", ""),
+            ("tabindex", "-1"),
+            ("type", "button"),
+        ]),
+        attributes! {
+            "aria-label" => label,
+            disabled,
+            "data-state" => ?maybe_state,
+            if open => "data-open",
+            if !open => hidden,
+            if open => tabindex => -1,
+            r#type => String::from("button"),
+        }
+    );
+
+    assert_eq!(
+        Attributes::from([("aria-busy", "true")]),
+        attributes! { aria_busy => ?Some("true"), "title" => ?None::<&str> }
+    );
+    assert_eq!(
+        Some(&None),
+        attributes! { "title" => None::<AttrValue> }.get("title")
+    );
+    assert_eq!(Attributes::default(), attributes! {});
+}
+
+#[test]
+fn test_with_defaults_merge() {
+    let attributes = Attributes::from([
+        ("class", "button primary"),
+        ("aria-describedby", "hint"),
+        ("title", "Save"),
+    ]);
+    let defaults = Attributes::from([
+        ("class", "button  base"),
+        ("aria-describedby", "error"),
+        ("title", "Button"),
+    ]);
+
+    assert_eq!(
+        Attributes::from([
+            ("class", "button base primary"),
+            ("aria-describedby", "hint"),
+            ("title", "Save"),
+        ]),
+        attributes.clone().with_defaults(defaults.clone())
+    );
+    assert_eq!(
+        Attributes::from([
+            ("class", "button base primary"),
+            ("aria-describedby", "error hint"),
+            ("title", "Save"),
+        ]),
+        attributes.with_defaults_by(defaults, |name| match name {
+            "aria-describedby" => Merge::Tokens,
+            name => Merge::for_attribute(name),
+        })
+    );
+
+    // Omitting a value still removes the default.
+    assert_eq!(
+        Some(&None),
+        attributes! { class => None::<AttrValue> }
+            .with_defaults([("class", "base")])
+            .get("class")
+    );
+}
+
+#[test]
+fn test_with_defaults_style() {
+    let style = Attributes::from([("style", "color: blue; margin: 0;")])
+        .with_defaults([("style", "color: red; padding: 0")]);
+
+    #[cfg(feature = "style")]
+    assert_eq!(
+        Attributes::from([("style", "color: blue; padding: 0; margin: 0;")]),
+        style
+    );
+    #[cfg(not(feature = "style"))]
+    assert_eq!(
+        Attributes::from([("style", "color: red; padding: 0; color: blue; margin: 0;")]),
+        style
+    );
+}
+
+#[cfg(feature = "style")]
+#[test]
+fn test_style_value() {
+    let style = yew_style::Style::from([("color", "red")]);
+
+    assert_eq!(
+        Attributes::from([("style", "color: red;")]),
+        attributes! { style => &style }
+    );
+    assert_eq!(
+        Some(&None),
+        attributes! { style => yew_style::Style::default() }.get("style")
+    );
+}
+
+#[test]
+fn test_split() {
+    let attributes = attributes! {
+        r#type => "submit",
+        disabled,
+        "aria-pressed" => "false",
+        tabindex => 2,
+        "data-state" => "open",
+        title => None::<AttrValue>,
+    };
+
+    let (mut picked, mut rest) =
+        attributes.split(&["type", "disabled", "aria-pressed", "tabindex", "title"]);
+
+    assert_eq!(Attributes::from([("data-state", "open")]), rest);
+    assert_eq!(Some(AttrValue::from("open")), rest.take("data-state"));
+    assert!(rest.is_empty());
+
+    assert_eq!(Some(AttrValue::from("submit")), picked.take("type"));
+    assert!(picked.take_bool("disabled"));
+    assert!(!picked.take_bool("aria-pressed"));
+    assert!(!picked.take_bool("hidden"));
+    assert_eq!(Ok(Some(2)), picked.take_parsed::<i32>("tabindex"));
+    assert_eq!(Ok(None), picked.take_parsed::<i32>("title"));
+    assert!(picked.is_empty());
+
+    let mut attributes = attributes! { tabindex => "first" };
+    assert!(attributes.take_parsed::<i32>("tabindex").is_err());
+    assert!(!attributes.contains("tabindex"));
+}
+
+#[test]
+fn test_normalize() {
+    let mut attributes =
+        attributes! { "ID" => "first", "aria-Label" => "Close", viewbox => "0 0 24 24" };
+    attributes.insert("id", "second");
+
+    assert_eq!(
+        Attributes::from([
+            ("id", "second"),
+            ("aria-label", "Close"),
+            ("viewBox", "0 0 24 24"),
+        ]),
+        attributes
+    );
+    assert!(attributes.contains("Id"));
+    assert!(attributes.contains("VIEWBOX"));
+    assert_eq!(
+        Some(Some(AttrValue::from("Close"))),
+        attributes.remove("ARIA-LABEL")
+    );
+
+    // The normalized spelling wins regardless of iteration order.
+    assert_eq!(
+        Attributes::from([("id", "lower")]),
+        Attributes::from(HashMap::from([
+            ("ID", "upper"),
+            ("id", "lower"),
+            ("Id", "mixed")
+        ]))
+    );
+    assert_eq!(
+        Attributes::from([("id", "upper")]),
+        Attributes::from(vec![("Id", "mixed"), ("ID", "upper")])
+    );
+
+    assert_eq!(
+        Attributes::from([("id", "user"), ("class", "base button")]),
+        Attributes::from([("Id", "user"), ("CLASS", "button")])
+            .with_defaults([("ID", "default"), ("class", "base")])
+    );
+
+    let (picked, rest) = attributes.split(&["ID"]);
+    assert_eq!(Attributes::from([("id", "second")]), picked);
+    assert_eq!(Attributes::from([("viewBox", "0 0 24 24")]), rest);
+}
@@ -0,0 +1,168 @@
+use yew::{ServerRenderer, prelude::*};
+use yew_struct_component::{
+    AttributeDefaults, Attributes, AttributesProvider, StructComponent, use_provided_attributes,
+};
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "button", provided_attributes)]
+struct ButtonChildProps {
+    pub attributes: Attributes,
+
+    pub lang: Option<String>,
+}
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "span", provided_attributes)]
+struct BadgeChildProps {
+    pub attributes: Attributes,
+}
+
+fn side_tokens(sides: Vec<&'static str>) -> Option<AttrValue> {
+    (!sides.is_empty()).then(|| AttrValue::from(sides.join(" ")))
+}
+
+fn has_sides(sides: &[&'static str]) -> bool {
+    !sides.is_empty()
+}
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "div", provided_attributes)]
+struct PanelChildProps {
+    pub attributes: Attributes,
+
+    pub hidden: bool,
+    pub inert: bool,
+    #[struct_component(with = side_tokens, is_set = has_sides)]
+    pub data_sides: Vec<&'static str>,
+}
+
+#[derive(PartialEq, Properties)]
+struct ButtonProps {
+    #[prop_or_default]
+    pub attributes: Attributes,
+    #[prop_or_default]
+    pub lang: Option<String>,
+}
+
+#[function_component]
+fn Button(props: &ButtonProps) -> Html {
+    use_provided_attributes(ButtonChildProps {
+        attributes: props.attributes.clone(),
+        lang: props.lang.clone(),
+    })
+    .render(html! { "Button" })
+}
+
+#[function_component]
+fn Badge() -> Html {
+    use_provided_attributes(BadgeChildProps {
+        attributes: Attributes::default(),
+    })
+    .render(html! { "Badge" })
+}
+
+#[function_component]
+fn App() -> Html {
+    html! {
+        <AttributesProvider
+            defaults={AttributeDefaults::new()
+                .all([("dir", "ltr"), ("lang", "en")])
+                .component::<BadgeChildProps, _>([("data-size", "small")])}
+        >
+            <Button />
+            <Button attributes={[("dir", "rtl")]} lang="nl" />
+            <AttributesProvider defaults={AttributeDefaults::new().all([("data-theme", "dark")])}>
+                <Badge />
+            </AttributesProvider>
+        </AttributesProvider>
+    }
+}
+
+#[tokio::test]
+async fn test() {
+    let renderer = ServerRenderer::<App>::new().hydratable(false);
+    let html = renderer.render().await;
+
+    // Attributes are stored in a hash map, so their order is not stable.
+    let (button, rest) = html
+        .split_once("</button>")
+        .expect("Button should be rendered.");
+    for attribute in [r#"dir="ltr""#, r#"lang="en""#] {
+        assert!(
+            button.contains(attribute),
+            "{button} should contain {attribute}"
+        );
+    }
+
+    // A set typed field takes precedence over the provided default.
+    assert_eq!(
+        r#"<button lang="nl" dir="rtl">Button"#,
+        &rest[..rest.find("</button>").expect("Button should be rendered.")]
+    );
+
+    let badge = &html[html.find("<span").expect("Badge should be rendered.")..];
+    for attribute in [
+        r#"dir="ltr""#,
+        r#"lang="en""#,
+        r#"data-size="small""#,
+        r#"data-theme="dark""#,
+    ] {
+        assert!(
+            badge.contains(attribute),
+            "{badge} should contain {attribute}"
+        );
+    }
+}
+
+#[derive(PartialEq, Properties)]
+struct PanelProps {
+    #[prop_or_default]
+    pub inert: bool,
+    #[prop_or_default]
+    pub sides: Vec<&'static str>,
+}
+
+#[function_component]
+fn Panel(props: &PanelProps) -> Html {
+    use_provided_attributes(PanelChildProps {
+        attributes: Attributes::default(),
+
+        hidden: false,
+        inert: props.inert,
+        data_sides: props.sides.clone(),
+    })
+    .render(html! {})
+}
+
+#[function_component]
+fn PanelApp() -> Html {
+    html! {
+        <AttributesProvider
+            defaults={AttributeDefaults::new().all([("hidden", ""), ("data-sides", "all")])}
+        >
+            <Panel inert=true />
+            <Panel sides={vec!["top"]} />
+        </AttributesProvider>
+    }
+}
+
+#[tokio::test]
+async fn test_unset_fields() {
+    let renderer = ServerRenderer::<PanelApp>::new().hydratable(false);
+    let html = renderer.render().await;
+    let (first, second) = html
+        .split_once("</div>")
+        .expect("Panels should be rendered.");
+
+    // A `false` boolean and a `with` field whose `is_set` returns `false` keep the provided defaults.
+    for attribute in [r#"inert="""#, r#"hidden="""#, r#"data-sides="all""#] {
+        assert!(
+            first.contains(attribute),
+            "{first} should contain {attribute}"
+        );
+    }
+
+    assert!(second.contains(r#"hidden="""#), "{second} should be hidden");
+    assert!(second.contains(r#"data-sides="top""#));
+    assert!(!second.contains(r#"data-sides="all""#));
+}
@@ -0,0 +1,10 @@
+use yew_struct_component::StructComponent;
+
+#[derive(StructComponent)]
+#[struct_component(tag = "div")]
+struct Props {
+    #[struct_component(dynamic_tag)]
+    pub r#as: String,
+}
+
+fn main() {}
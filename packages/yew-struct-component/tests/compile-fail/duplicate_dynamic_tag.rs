@@ -0,0 +1,11 @@
+use yew_struct_component::StructComponent;
+
+#[derive(StructComponent)]
+struct Props {
+    #[struct_component(dynamic_tag)]
+    pub r#as: String,
+    #[struct_component(dynamic_tag = true)]
+    pub element: String,
+}
+
+fn main() {}
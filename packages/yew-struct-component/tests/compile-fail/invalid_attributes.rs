@@ -0,0 +1,10 @@
+use yew_struct_component::attributes;
+
+fn main() {
+    let _ = attributes! {
+        "" => "empty",
+        "data state" => "open",
+        "aria-label=" => "Close",
+        disabled,
+    };
+}
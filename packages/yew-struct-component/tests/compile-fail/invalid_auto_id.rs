@@ -0,0 +1,14 @@
+use yew_struct_component::StructComponent;
+
+#[derive(StructComponent)]
+#[struct_component(tag = "div")]
+struct Props {
+    #[struct_component(auto_id)]
+    pub id: String,
+    #[struct_component(auto_id)]
+    pub aria_labelledby: Option<String>,
+    #[struct_component(auto_id)]
+    pub aria_describedby: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use yew::prelude::*;
+use yew_struct_component::StructComponent;
+
+#[derive(StructComponent)]
+#[struct_component(tag = "input", no_children = true)]
+struct Props {
+    #[struct_component(bind = on_value_chnage)]
+    pub value: Option<String>,
+    #[struct_component(bind = on_value_change)]
+    pub name: Option<String>,
+    pub on_value_change: Callback<String>,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use yew_struct_component::StructComponent;
+
+#[derive(StructComponent)]
+#[struct_component(tag = "div", tag = "span", children)]
+struct Props {
+    #[struct_component(no_children)]
+    pub id: Option<String>,
+    #[struct_component(children, text)]
+    pub label: String,
+    #[struct_component(is_set = Vec::is_empty)]
+    pub data_sides: Vec<String>,
+}
+
+fn main() {}
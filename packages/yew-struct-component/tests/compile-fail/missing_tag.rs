@@ -0,0 +1,8 @@
+use yew_struct_component::StructComponent;
+
+#[derive(StructComponent)]
+struct Props {
+    pub id: Option<String>,
+}
+
+fn main() {}
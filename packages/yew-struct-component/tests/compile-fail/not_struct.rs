@@ -0,0 +1,9 @@
+use yew_struct_component::StructComponent;
+
+#[derive(StructComponent)]
+#[struct_component(tag = "div")]
+enum Props {
+    Div,
+}
+
+fn main() {}
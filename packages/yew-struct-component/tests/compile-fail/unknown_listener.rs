@@ -0,0 +1,12 @@
+use yew::prelude::*;
+use yew_struct_component::StructComponent;
+
+#[derive(StructComponent)]
+#[struct_component(tag = "button")]
+struct Props {
+    pub onclik: Callback<MouseEvent>,
+    pub onkeydwn: Callback<KeyboardEvent>,
+    pub onselectitem: Callback<String>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use yew_struct_component::StructComponent;
+
+#[derive(StructComponent)]
+#[struct_component(tga = "div", no_childen)]
+struct Props {
+    #[struct_component(txt)]
+    pub label: String,
+}
+
+fn main() {}
@@ -0,0 +1,98 @@
+use yew::{ServerRenderer, prelude::*};
+use yew_struct_component::StructComponent;
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "label")]
+struct LabelChildProps {
+    pub id: Option<String>,
+
+    #[struct_component(text)]
+    pub label: AttrValue,
+    #[struct_component(children)]
+    pub children: Option<Html>,
+}
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "div")]
+struct ProseChildProps {
+    pub class: Option<String>,
+
+    #[struct_component(inner_html)]
+    pub content: Option<AttrValue>,
+}
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "p")]
+struct ParagraphChildProps {
+    #[struct_component(children)]
+    pub children: Html,
+}
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "section")]
+struct SectionChildProps {
+    #[struct_component(children)]
+    pub children: Children,
+}
+
+#[derive(PartialEq, Properties)]
+struct SectionProps {
+    pub children: Children,
+}
+
+#[function_component]
+fn Section(props: &SectionProps) -> Html {
+    let child_props = SectionChildProps {
+        children: props.children.clone(),
+    };
+
+    html! {
+        <>
+            {child_props.render_ref()}
+            {child_props.render()}
+        </>
+    }
+}
+
+#[function_component]
+fn App() -> Html {
+    html! {
+        <>
+            {LabelChildProps {
+                id: Some("label".to_owned()),
+
+                label: "<Name>".into(),
+                children: Some(html! {
+                    <input />
+                }),
+            }
+            .render()}
+            {ProseChildProps {
+                class: None,
+
+                content: Some("<p>Trusted</p>".into()),
+            }
+            .render()}
+            {ParagraphChildProps {
+                children: html! { <em>{"Text"}</em> },
+            }
+            .render()}
+            <Section>{"One"}<hr /></Section>
+        </>
+    }
+}
+
+#[tokio::test]
+async fn test() {
+    let renderer = ServerRenderer::<App>::new().hydratable(false);
+    let html = renderer.render().await;
+
+    assert_eq!(
+        concat!(
+            r#"<label id="label">&lt;Name&gt;<input></label><div><p>Trusted</p></div>"#,
+            r#"<p><em>Text</em></p>"#,
+            r#"<section>One<hr></section><section>One<hr></section>"#,
+        ),
+        html
+    );
+}
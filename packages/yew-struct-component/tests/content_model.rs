@@ -0,0 +1,102 @@
+#![cfg(feature = "validate")]
+
+use yew::prelude::*;
+use yew_struct_component::{ContentModelIssue, StructComponent, content_model_issues};
+
+#[derive(Clone, PartialEq, StructComponent)]
+struct BoxChildProps {
+    #[struct_component(dynamic_tag)]
+    pub r#as: String,
+}
+
+fn issue(path: &str, message: &str) -> ContentModelIssue {
+    ContentModelIssue {
+        path: path.to_string(),
+        message: message.to_string(),
+    }
+}
+
+fn render(r#as: &str, children: Html) -> Html {
+    BoxChildProps {
+        r#as: r#as.to_string(),
+    }
+    .render(children)
+}
+
+#[test]
+fn test_phrasing() {
+    assert_eq!(
+        vec![issue(
+            "p > a > div",
+            "`<div>` is not phrasing content, but `<p>` only allows phrasing content"
+        )],
+        content_model_issues(&render(
+            "p",
+            html! { <a href="/">{render("div", html! { "Home" })}</a> }
+        ))
+    );
+
+    assert_eq!(
+        Vec::<ContentModelIssue>::new(),
+        content_model_issues(&render("div", html! { <a href="/"><div /></a> }))
+    );
+}
+
+#[test]
+fn test_interactive() {
+    assert_eq!(
+        vec![issue(
+            "button > span > button",
+            "interactive `<button>` is not allowed inside `<button>`"
+        )],
+        content_model_issues(&render(
+            "button",
+            html! { <span>{render("button", html! { "Close" })}</span> }
+        ))
+    );
+
+    assert_eq!(
+        vec![issue(
+            "button > input",
+            "interactive `<input>` is not allowed inside `<button>`"
+        )],
+        content_model_issues(&render(
+            "button",
+            html! { <><input type="hidden" /><input type="checkbox" /></> }
+        ))
+    );
+}
+
+#[test]
+fn test_table() {
+    assert_eq!(
+        vec![
+            issue("table > td", "`<td>` is not allowed in `<table>`"),
+            issue("table > tbody > div", "`<div>` is not allowed in `<tbody>`"),
+            issue("table > tbody > tr", "text is not allowed in `<tr>`"),
+            issue(
+                "table > tr",
+                "`<tr>` is not allowed in `<table>`, browsers insert a `<tbody>` around it"
+            ),
+            issue(
+                "div > tr",
+                "`<tr>` must be a child of `<thead>` or `<tbody>` or `<tfoot>`"
+            ),
+        ],
+        content_model_issues(&html! {
+            <>
+                {render("table", html! {
+                    <>
+                        <td />
+                        <tbody>
+                            <div />
+                            <tr>{"Text"}<td>{"Cell"}</td></tr>
+                        </tbody>
+                        <tr />
+                    </>
+                })}
+                {render("div", html! { <tr /> })}
+            </>
+        })
+    );
+}
@@ -0,0 +1,194 @@
+use std::{cell::RefCell, rc::Rc};
+
+use yew::{LocalServerRenderer, ServerRenderer, prelude::*, suspense::Suspension};
+use yew_struct_component::{
+    AttributeKind, StructComponent, StructComponentMeta, UseControllableStateParams,
+    use_controllable_state,
+};
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "input", no_children = true)]
+struct TextFieldChildProps {
+    #[struct_component(bind = on_value_change)]
+    pub value: Option<String>,
+    pub on_value_change: Callback<String>,
+}
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "input", no_children = true)]
+struct CheckboxChildProps {
+    pub r#type: AttrValue,
+    #[struct_component(bind = on_checked_change)]
+    pub checked: bool,
+    pub on_checked_change: Option<Callback<bool>>,
+}
+
+#[derive(PartialEq, Properties)]
+struct TextFieldProps {
+    #[prop_or_default]
+    pub value: Option<String>,
+    #[prop_or_default]
+    pub default_value: Option<String>,
+    #[prop_or_default]
+    pub on_value_change: Option<Callback<String>>,
+}
+
+#[function_component]
+fn TextField(props: &TextFieldProps) -> Html {
+    let (value, set_value) = use_controllable_state(UseControllableStateParams {
+        prop: props.value.clone(),
+        default_prop: props.default_value.clone(),
+        on_change: props.on_value_change.clone(),
+    });
+
+    let text_field = TextFieldChildProps {
+        value: value.clone(),
+        on_value_change: set_value,
+    }
+    .into_vtag();
+
+    html! {
+        <span>{value}{text_field.value().is_some().then_some("!")}</span>
+    }
+}
+
+#[function_component]
+fn App() -> Html {
+    html! {
+        <>
+            <TextField default_value="uncontrolled" />
+            <TextField value="controlled" default_value="uncontrolled" />
+            <TextField />
+        </>
+    }
+}
+
+#[tokio::test]
+async fn test() {
+    let renderer = ServerRenderer::<App>::new().hydratable(false);
+    let html = renderer.render().await;
+
+    assert_eq!(
+        concat!(
+            r#"<span>uncontrolled!</span>"#,
+            r#"<span>controlled!</span>"#,
+            r#"<span></span>"#,
+        ),
+        html
+    );
+}
+
+#[test]
+fn test_bind() {
+    let checkbox = CheckboxChildProps {
+        r#type: AttrValue::Static("checkbox"),
+        checked: true,
+        on_checked_change: Some(Callback::noop()),
+    }
+    .into_vtag();
+
+    assert_eq!(Some(true), checkbox.checked());
+
+    assert_eq!(
+        Some(AttributeKind::Listener),
+        TextFieldChildProps::attribute("oninput").map(|attribute| attribute.kind)
+    );
+    assert_eq!(
+        vec!["on_checked_change"],
+        CheckboxChildProps::listeners()
+            .map(|listener| listener.field)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[derive(Properties)]
+struct SetValueProps {
+    #[prop_or_default]
+    pub value: Option<String>,
+    #[prop_or_default]
+    pub default_value: Option<String>,
+    pub changes: Rc<RefCell<Vec<String>>>,
+}
+
+impl PartialEq for SetValueProps {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+            && self.default_value == other.default_value
+            && Rc::ptr_eq(&self.changes, &other.changes)
+    }
+}
+
+/// Emits the setter once, like the bound listener would on input, and suspends to render the next state.
+#[function_component]
+fn SetValue(props: &SetValueProps) -> HtmlResult {
+    let changes = props.changes.clone();
+    let (value, set_value) = use_controllable_state(UseControllableStateParams {
+        prop: props.value.clone(),
+        default_prop: props.default_value.clone(),
+        on_change: Some(Callback::from(move |value: String| {
+            changes.borrow_mut().push(value)
+        })),
+    });
+
+    let emitted = use_mut_ref(|| false);
+    if !*emitted.borrow() {
+        *emitted.borrow_mut() = true;
+        set_value.emit("next".to_owned());
+        // Setting the current value again is ignored.
+        set_value.emit(value.clone().unwrap_or_default());
+
+        return Err(Suspension::from_future(async {}).into());
+    }
+
+    Ok(TextFieldChildProps {
+        value,
+        on_value_change: set_value,
+    }
+    .render())
+}
+
+#[derive(Properties)]
+struct SetValueAppProps {
+    pub value: Option<String>,
+    pub changes: Rc<RefCell<Vec<String>>>,
+}
+
+impl PartialEq for SetValueAppProps {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && Rc::ptr_eq(&self.changes, &other.changes)
+    }
+}
+
+#[function_component]
+fn SetValueApp(props: &SetValueAppProps) -> Html {
+    html! {
+        <Suspense>
+            <SetValue
+                value={props.value.clone()}
+                default_value="uncontrolled"
+                changes={props.changes.clone()}
+            />
+        </Suspense>
+    }
+}
+
+#[tokio::test]
+async fn test_set_value() {
+    for (value, expected) in [(None, "next"), (Some("controlled"), "controlled")] {
+        let changes = Rc::new(RefCell::new(vec![]));
+        let props = SetValueAppProps {
+            value: value.map(ToOwned::to_owned),
+            changes: changes.clone(),
+        };
+
+        // Suspensions are resumed on local tasks.
+        let renderer = LocalServerRenderer::<SetValueApp>::with_props(props).hydratable(false);
+        let html = tokio::task::LocalSet::new()
+            .run_until(renderer.render())
+            .await;
+
+        // Controlled state only changes through the prop, but both report the change.
+        assert_eq!(format!(r#"<input value="{expected}">"#), html);
+        assert_eq!(vec!["next".to_owned()], *changes.borrow());
+    }
+}
@@ -0,0 +1,74 @@
+mod facade {
+    pub use yew as ui;
+    pub use yew_struct_component as struct_component;
+}
+
+mod components {
+    #![no_implicit_prelude]
+
+    use crate::facade::struct_component::{AttrValueEnum, Attributes, StructComponent, attributes};
+
+    #[derive(AttrValueEnum, Clone, Copy)]
+    #[attr_value(crate = "crate::facade::struct_component", rename_all = "lowercase")]
+    pub enum State {
+        Open,
+    }
+
+    #[derive(Clone, StructComponent)]
+    #[struct_component(
+        crate = "crate::facade::struct_component",
+        yew = "crate::facade::ui",
+        tag = "details"
+    )]
+    pub struct DetailsChildProps {
+        pub attributes: Attributes,
+
+        pub id: ::std::option::Option<::std::string::String>,
+        pub open: bool,
+        pub data_state: State,
+    }
+
+    // Properties generate extra code, which must not rely on the prelude either.
+    #[derive(Clone, StructComponent)]
+    #[struct_component(
+        crate = "crate::facade::struct_component",
+        yew = "crate::facade::ui",
+        tag = "input",
+        no_children
+    )]
+    pub struct InputChildProps {
+        pub value: ::std::string::String,
+        pub checked: bool,
+    }
+
+    pub fn title() -> Attributes {
+        attributes! { crate = "crate::facade::struct_component"; title => "Details" }
+    }
+}
+
+use yew::{ServerRenderer, prelude::*};
+
+use crate::components::{DetailsChildProps, State, title};
+
+#[function_component]
+fn App() -> Html {
+    DetailsChildProps {
+        attributes: title(),
+
+        id: Some("details".to_owned()),
+        open: true,
+        data_state: State::Open,
+    }
+    .render(html! { "Summary" })
+}
+
+#[tokio::test]
+async fn test() {
+    let renderer = ServerRenderer::<App>::new().hydratable(false);
+    let html = renderer.render().await;
+
+    assert_eq!(
+        r#"<details id="details" open="" data-state="open" title="Details">Summary</details>"#,
+        html
+    );
+}
@@ -0,0 +1,125 @@
+use yew::{LocalServerRenderer, ServerRenderer, prelude::*};
+use yew_struct_component::{IdProvider, StructComponent, use_auto_id, use_id};
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "h2")]
+struct DialogTitleChildProps {
+    #[struct_component(auto_id)]
+    pub id: Option<String>,
+}
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "div")]
+struct DialogContentChildProps {
+    pub role: AttrValue,
+    pub aria_labelledby: Option<String>,
+    pub aria_describedby: Option<AttrValue>,
+}
+
+#[derive(PartialEq, Properties)]
+struct DialogProps {
+    #[prop_or_default]
+    pub title_id: Option<String>,
+}
+
+#[function_component]
+fn Dialog(props: &DialogProps) -> Html {
+    let description_id = use_id(None);
+    let title = use_auto_id(DialogTitleChildProps {
+        id: props.title_id.clone(),
+    });
+
+    DialogContentChildProps {
+        role: AttrValue::Static("dialog"),
+        aria_labelledby: title.id.clone(),
+        aria_describedby: Some(description_id.clone()),
+    }
+    .render(html! {
+        <>
+            {title.render(html! { "Title" })}
+            <p id={description_id}>{"Description"}</p>
+        </>
+    })
+}
+
+#[function_component]
+fn App() -> Html {
+    html! {
+        <IdProvider>
+            <Dialog />
+            <Dialog title_id="title" />
+        </IdProvider>
+    }
+}
+
+#[tokio::test]
+async fn test() {
+    let renderer = ServerRenderer::<App>::new().hydratable(false);
+
+    let expected = concat!(
+        r#"<div role="dialog" aria-labelledby="yew-id-1" aria-describedby="yew-id-0">"#,
+        r#"<h2 id="yew-id-1">Title</h2><p id="yew-id-0">Description</p>"#,
+        r#"</div>"#,
+        r#"<div role="dialog" aria-labelledby="title" aria-describedby="yew-id-2">"#,
+        r#"<h2 id="title">Title</h2><p id="yew-id-2">Description</p>"#,
+        r#"</div>"#,
+    );
+
+    // Each render starts a new scope, so ids are stable across renders.
+    assert_eq!(expected, renderer.render().await);
+    assert_eq!(
+        expected,
+        ServerRenderer::<App>::new()
+            .hydratable(false)
+            .render()
+            .await
+    );
+}
+
+#[function_component]
+fn Description() -> Html {
+    let id = use_id(None);
+
+    html! { <p id={id}>{"Description"}</p> }
+}
+
+#[function_component]
+fn SiblingApp() -> Html {
+    html! {
+        <>
+            <IdProvider>
+                <IdProvider><Description /></IdProvider>
+                <IdProvider><Description /></IdProvider>
+            </IdProvider>
+            <IdProvider prefix="first"><Description /></IdProvider>
+            <IdProvider prefix="second"><Description /></IdProvider>
+        </>
+    }
+}
+
+#[tokio::test]
+async fn test_sibling_providers() {
+    let renderer = ServerRenderer::<SiblingApp>::new().hydratable(false);
+
+    assert_eq!(
+        concat!(
+            r#"<p id="yew-id-0">Description</p><p id="yew-id-1">Description</p>"#,
+            r#"<p id="first-0">Description</p><p id="second-0">Description</p>"#,
+        ),
+        renderer.render().await
+    );
+}
+
+#[function_component]
+fn Unscoped() -> Html {
+    html! { <Dialog /> }
+}
+
+#[tokio::test]
+#[should_panic(expected = "`use_id` requires an `IdProvider` ancestor")]
+async fn test_without_provider() {
+    LocalServerRenderer::<Unscoped>::new()
+        .hydratable(false)
+        .render()
+        .await;
+}
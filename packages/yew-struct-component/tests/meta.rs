@@ -0,0 +1,107 @@
+use yew::prelude::*;
+use yew_struct_component::{
+    AttributeKind, AttributeMeta, Attributes, StructComponent, StructComponentMeta,
+};
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "input")]
+struct InputChildProps {
+    pub attributes: Attributes,
+    pub node_ref: NodeRef,
+
+    pub aria_label: String,
+    pub disabled: bool,
+    pub r#type: Option<String>,
+    pub value: AttrValue,
+
+    pub oninput: Callback<InputEvent>,
+}
+
+#[derive(Clone, PartialEq, StructComponent)]
+struct HeadingChildProps {
+    #[struct_component(dynamic_tag)]
+    pub r#as: String,
+    #[struct_component(text)]
+    pub text: Option<String>,
+}
+
+#[test]
+fn test() {
+    assert_eq!(Some("input"), InputChildProps::TAG);
+    assert_eq!(
+        &[
+            AttributeMeta {
+                field: "attributes",
+                name: "",
+                kind: AttributeKind::Attributes,
+                optional: true,
+            },
+            AttributeMeta {
+                field: "node_ref",
+                name: "",
+                kind: AttributeKind::NodeRef,
+                optional: true,
+            },
+            AttributeMeta {
+                field: "aria_label",
+                name: "aria-label",
+                kind: AttributeKind::Attribute,
+                optional: false,
+            },
+            AttributeMeta {
+                field: "disabled",
+                name: "disabled",
+                kind: AttributeKind::Attribute,
+                optional: true,
+            },
+            AttributeMeta {
+                field: "type",
+                name: "type",
+                kind: AttributeKind::Attribute,
+                optional: true,
+            },
+            AttributeMeta {
+                field: "value",
+                name: "value",
+                kind: AttributeKind::Property,
+                optional: false,
+            },
+            AttributeMeta {
+                field: "oninput",
+                name: "oninput",
+                kind: AttributeKind::Listener,
+                optional: false,
+            },
+        ],
+        InputChildProps::ATTRIBUTES
+    );
+    assert_eq!(
+        Some(AttributeKind::Attribute),
+        InputChildProps::attribute("aria-label").map(|attribute| attribute.kind)
+    );
+    assert_eq!(
+        vec!["oninput"],
+        InputChildProps::listeners()
+            .map(|listener| listener.name)
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(None, HeadingChildProps::TAG);
+    assert_eq!(
+        &[
+            AttributeMeta {
+                field: "as",
+                name: "",
+                kind: AttributeKind::DynamicTag,
+                optional: false,
+            },
+            AttributeMeta {
+                field: "text",
+                name: "",
+                kind: AttributeKind::Content,
+                optional: true,
+            },
+        ],
+        HeadingChildProps::ATTRIBUTES
+    );
+}
@@ -0,0 +1,59 @@
+use yew::{ServerRenderer, prelude::*, virtual_dom::VTag};
+use yew_struct_component::StructComponent;
+
+fn side_tokens(sides: Vec<&'static str>) -> Option<AttrValue> {
+    (!sides.is_empty()).then(|| AttrValue::from(sides.join(" ")))
+}
+
+fn fixed_precision(value: f64) -> Option<AttrValue> {
+    Some(AttrValue::from(format!("{value:.2}")))
+}
+
+fn add_slot(tag: &mut VTag) {
+    tag.add_attribute("data-slot", "progress");
+}
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "div", no_children = true, finish = add_slot)]
+struct ProgressChildProps {
+    pub id: Option<String>,
+
+    #[struct_component(with = side_tokens)]
+    pub data_sides: Vec<&'static str>,
+    #[struct_component(with = fixed_precision)]
+    pub aria_valuenow: f64,
+}
+
+#[function_component]
+fn App() -> Html {
+    html! {
+        <>
+            {ProgressChildProps {
+                id: None,
+
+                data_sides: vec!["top", "left"],
+                aria_valuenow: 1.0 / 3.0,
+            }}
+            {ProgressChildProps {
+                id: None,
+
+                data_sides: vec![],
+                aria_valuenow: 0.5,
+            }}
+        </>
+    }
+}
+
+#[tokio::test]
+async fn test() {
+    let renderer = ServerRenderer::<App>::new().hydratable(false);
+    let html = renderer.render().await;
+
+    assert_eq!(
+        concat!(
+            r#"<div data-sides="top left" aria-valuenow="0.33" data-slot="progress"></div>"#,
+            r#"<div aria-valuenow="0.50" data-slot="progress"></div>"#,
+        ),
+        html
+    );
+}
@@ -0,0 +1,103 @@
+use std::rc::Rc;
+
+use yew::{ServerRenderer, prelude::*};
+use yew_struct_component::{Attributes, StructComponent};
+
+#[derive(PartialEq, StructComponent)]
+#[struct_component(tag = "a")]
+struct LinkChildProps {
+    pub attributes: Attributes,
+
+    pub href: AttrValue,
+    pub title: Option<AttrValue>,
+
+    pub onclick: Callback<MouseEvent>,
+}
+
+#[function_component]
+fn App() -> Html {
+    let child_props = LinkChildProps {
+        attributes: Attributes::from([("rel", "noopener")]),
+
+        href: AttrValue::Static("/home"),
+        title: Some(AttrValue::Static("Home")),
+
+        onclick: Callback::noop(),
+    };
+
+    html! {
+        <>
+            {child_props.render_ref(html! { "Home" })}
+            {child_props.to_vtag(html! { "Back" })}
+        </>
+    }
+}
+
+#[tokio::test]
+async fn test() {
+    let renderer = ServerRenderer::<App>::new().hydratable(false);
+    let html = renderer.render().await;
+
+    assert_eq!(
+        concat!(
+            r#"<a href="/home" title="Home" rel="noopener">Home</a>"#,
+            r#"<a href="/home" title="Home" rel="noopener">Back</a>"#,
+        ),
+        html
+    );
+}
+
+/// Content which can only be rendered by reference, not cloned.
+#[derive(PartialEq)]
+struct Label(&'static str);
+
+impl ToHtml for Label {
+    fn to_html(&self) -> Html {
+        html! { self.0 }
+    }
+}
+
+#[derive(PartialEq, StructComponent)]
+#[struct_component(tag = "span")]
+struct LabelChildProps {
+    pub hidden: bool,
+    #[struct_component(text)]
+    pub label: Label,
+}
+
+#[test]
+fn test_borrowed_content() {
+    let child_props = LabelChildProps {
+        hidden: true,
+        label: Label("Label"),
+    };
+
+    let tag = child_props.to_vtag();
+    assert_eq!("span", tag.tag());
+    assert_eq!(
+        Some(""),
+        tag.attributes
+            .iter()
+            .find(|(key, _)| *key == "hidden")
+            .map(|(_, value)| value)
+    );
+}
+
+#[test]
+fn test_attributes_clone() {
+    let attributes = Attributes::from([("rel", "noopener")]);
+    let mut clone = attributes.clone();
+
+    assert!(Rc::ptr_eq(
+        attributes.as_ref().expect("Map should exist."),
+        clone.as_ref().expect("Map should exist."),
+    ));
+
+    clone.insert("target", AttrValue::Static("_blank"));
+
+    assert_eq!(Attributes::from([("rel", "noopener")]), attributes);
+    assert_eq!(
+        Attributes::from([("rel", "noopener"), ("target", "_blank")]),
+        clone
+    );
+}
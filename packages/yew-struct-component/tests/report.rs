@@ -0,0 +1,118 @@
+#![cfg(all(debug_assertions, any(feature = "a11y", feature = "validate")))]
+
+use std::cell::RefCell;
+
+use log::{LevelFilter, Log, Metadata, Record};
+use yew::prelude::*;
+use yew_struct_component::StructComponent;
+
+thread_local! {
+    static WARNINGS: RefCell<Vec<String>> = const { RefCell::new(vec![]) };
+}
+
+/// Collects the messages logged by the current test thread.
+struct Logger;
+
+impl Log for Logger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        WARNINGS.with_borrow_mut(|warnings| warnings.push(record.args().to_string()));
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: Logger = Logger;
+
+fn warnings(f: impl FnOnce()) -> Vec<String> {
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(LevelFilter::Warn);
+
+    f();
+
+    WARNINGS.take()
+}
+
+#[cfg(feature = "a11y")]
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "button")]
+struct IconButtonChildProps {
+    pub title: Option<String>,
+}
+
+#[cfg(feature = "a11y")]
+#[test]
+fn test_a11y() {
+    let props = IconButtonChildProps { title: None };
+
+    // Tags built with `to_vtag` may still be completed by the caller.
+    assert_eq!(
+        Vec::<String>::new(),
+        warnings(|| {
+            let mut tag = props.to_vtag(html! { <svg /> });
+            tag.add_attribute("aria-label", "Close");
+        })
+    );
+
+    assert_eq!(
+        vec!["<button>: missing accessible name".to_string()],
+        warnings(|| {
+            let _ = props.render_ref(html! { <svg /> });
+        })
+    );
+}
+
+#[cfg(feature = "validate")]
+#[derive(Clone, PartialEq, StructComponent)]
+struct BoxChildProps {
+    #[struct_component(dynamic_tag)]
+    pub r#as: String,
+}
+
+#[cfg(feature = "validate")]
+fn render(r#as: &str, children: Html) -> Html {
+    BoxChildProps {
+        r#as: r#as.to_string(),
+    }
+    .render(children)
+}
+
+#[cfg(feature = "validate")]
+#[test]
+fn test_content_model() {
+    // The issue is reported by `<p>`, not again by the ancestors which contain it.
+    assert_eq!(
+        vec![
+            "p > a > div: `<div>` is not phrasing content, but `<p>` only allows phrasing content"
+                .to_string()
+        ],
+        warnings(|| {
+            let _ = render(
+                "section",
+                render(
+                    "article",
+                    render("p", render("a", render("div", html! { "Text" }))),
+                ),
+            );
+        })
+    );
+
+    assert_eq!(
+        vec![
+            "button > span > button: interactive `<button>` is not allowed inside `<button>`"
+                .to_string()
+        ],
+        warnings(|| {
+            let _ = render(
+                "div",
+                render(
+                    "button",
+                    html! { <span>{render("button", html! { "Close" })}</span> },
+                ),
+            );
+        })
+    );
+}
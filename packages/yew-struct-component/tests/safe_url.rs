@@ -0,0 +1,83 @@
+use yew::{ServerRenderer, prelude::*};
+use yew_struct_component::{Attributes, Policy, SafeUrl, SafeUrlError, StructComponent};
+
+const HTTPS_POLICY: Policy = Policy {
+    url_schemes: &["https"],
+    relative_urls: false,
+    ..Policy::DEFAULT
+};
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "a")]
+struct LinkChildProps {
+    pub attributes: Attributes,
+
+    pub href: SafeUrl,
+    pub ping: Option<SafeUrl>,
+}
+
+#[function_component]
+fn App() -> Html {
+    let href = SafeUrl::parse("/search?q=safe url").expect("URL should be safe.");
+
+    html! {
+        <>
+            {LinkChildProps {
+                attributes: Attributes::from([("data-href", href.clone())]),
+                href,
+                ping: None,
+            }
+            .render(html! { "Search" })}
+        </>
+    }
+}
+
+#[tokio::test]
+async fn test() {
+    let renderer = ServerRenderer::<App>::new().hydratable(false);
+
+    assert_eq!(
+        r#"<a href="/search?q=safe%20url" data-href="/search?q=safe%20url">Search</a>"#,
+        renderer.render().await
+    );
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(
+        "https://example.com/caf%C3%A9?q=%2Fa%25b%2F",
+        SafeUrl::parse(" HTTPS://example.com/café?q=%2fa%b%2f\n")
+            .expect("URL should be safe.")
+            .as_str()
+    );
+    assert_eq!(
+        "mailto:info@example.com",
+        SafeUrl::parse("MailTo:info@example.com")
+            .expect("URL should be safe.")
+            .as_str()
+    );
+    assert_eq!(
+        Err(SafeUrlError::DisallowedScheme("javascript".to_string())),
+        SafeUrl::parse("java\tscript:alert(1)")
+    );
+    assert_eq!(
+        Err(SafeUrlError::DisallowedScheme("http".to_string())),
+        SafeUrl::parse_with("http://example.com", &HTTPS_POLICY)
+    );
+    assert_eq!(
+        Err(SafeUrlError::Relative),
+        SafeUrl::parse_with("/home", &HTTPS_POLICY)
+    );
+    assert!("#top".parse::<SafeUrl>().is_ok());
+    assert!(SafeUrl::try_from("data:text/html,<script></script>").is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    assert_eq!(
+        SafeUrl::parse("/home").ok(),
+        serde_json::from_str::<SafeUrl>(r#""/home""#).ok()
+    );
+    assert!(serde_json::from_str::<SafeUrl>(r#""javascript:alert(1)""#).is_err());
+}
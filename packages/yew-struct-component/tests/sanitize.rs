@@ -0,0 +1,191 @@
+use yew::{ServerRenderer, prelude::*};
+use yew_struct_component::{Attributes, Policy, StructComponent, attributes};
+
+const IMAGE_POLICY: Policy = Policy {
+    url_schemes: &["https"],
+    allowed_attributes: &[("img", &["src", "alt"]), ("*", &["title"])],
+    ..Policy::DEFAULT
+};
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "a", sanitize)]
+struct LinkChildProps {
+    pub attributes: Attributes,
+
+    pub href: Option<AttrValue>,
+}
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(sanitize = IMAGE_POLICY, no_children)]
+struct ImageChildProps {
+    #[struct_component(dynamic_tag)]
+    pub r#as: String,
+
+    pub attributes: Attributes,
+
+    pub src: AttrValue,
+}
+
+#[function_component]
+fn App() -> Html {
+    html! {
+        <>
+            {LinkChildProps {
+                attributes: attributes! {
+                    onclick => "alert(1)",
+                    srcdoc => "<script></script>",
+                    title => "Home",
+                },
+                href: Some(AttrValue::Static(" java\tscript:alert(1)")),
+            }
+            .render(html! { "Home" })}
+            {LinkChildProps {
+                attributes: attributes! { ping => "/ping javascript:alert(1)" },
+                href: Some(AttrValue::Static("/home")),
+            }
+            .render(html! { "Home" })}
+            {ImageChildProps {
+                r#as: "img".to_string(),
+                attributes: attributes! {
+                    alt => "Logo",
+                    title => "Logo",
+                    "data-id" => 1,
+                    width => 32,
+                },
+                src: AttrValue::Static("http://example.com/logo.png"),
+            }}
+        </>
+    }
+}
+
+#[tokio::test]
+async fn test() {
+    let renderer = ServerRenderer::<App>::new().hydratable(false);
+    let html = renderer.render().await;
+
+    assert_eq!(
+        concat!(
+            r#"<a title="Home">Home</a>"#,
+            r#"<a href="/home">Home</a>"#,
+            r#"<img"#,
+        ),
+        &html[..html.find("<img").expect("Image should be rendered.") + 4]
+    );
+
+    // Attributes are stored in a hash map, so their order is not stable.
+    let image = &html[html.find("<img").expect("Image should be rendered.")..];
+    for attribute in [r#"alt="Logo""#, r#"title="Logo""#, r#"data-id="1""#] {
+        assert!(
+            image.contains(attribute),
+            "{image} should contain {attribute}"
+        );
+    }
+    for attribute in ["src", "width"] {
+        assert!(
+            !image.contains(attribute),
+            "{image} should not contain {attribute}"
+        );
+    }
+}
+
+#[test]
+fn test_policy() {
+    let policy = Policy::default();
+
+    assert!(policy.allows(None, "href", Some("https://example.com")));
+    assert!(policy.allows(None, "href", Some("mailto:info@example.com")));
+    assert!(policy.allows(None, "href", Some("#top")));
+    assert!(policy.allows(None, "href", Some("/path?next=javascript:alert(1)")));
+    assert!(policy.allows(None, "title", Some("javascript:alert(1)")));
+    assert!(!policy.allows(None, "href", Some("JavaScript:alert(1)")));
+    assert!(!policy.allows(None, "formaction", Some("\u{1}javascript:alert(1)")));
+    assert!(!policy.allows(None, "src", Some("data:text/html,<script></script>")));
+    assert!(!policy.allows(None, "srcset", Some("a.png 1x, javascript:alert(1) 2x")));
+    assert!(!policy.allows(None, "onError", Some("alert(1)")));
+    assert!(!policy.allows(None, "srcdoc", None));
+
+    assert!(policy.allows(Some("a"), "target", Some("_blank")));
+    assert!(policy.allows(Some("a"), "title", Some("Home")));
+    assert!(policy.allows(Some("img"), "data-id", Some("1")));
+    assert!(!policy.allows(Some("iframe"), "credentialless", None));
+    assert!(!policy.allows(Some("form"), "formaction", Some("/submit")));
+    assert!(policy.allows(Some("div"), "formaction", Some("/submit")));
+
+    assert!(!policy.allows(Some("meta"), "http-equiv", Some("refresh")));
+    assert!(!policy.allows(None, "HTTP-EQUIV", Some("refresh")));
+    assert!(!policy.allows(Some("base"), "href", Some("https://example.com")));
+    assert!(!policy.allows(Some("base"), "target", Some("_blank")));
+    assert!(policy.allows(Some("link"), "href", Some("/style.css")));
+    assert!(!policy.allows(Some("link"), "href", Some("javascript:alert(1)")));
+    assert!(!policy.allows(Some("link"), "imagesrcset", Some("javascript:alert(1) 2x")));
+    assert!(!policy.allows(Some("object"), "data", Some("/upload.svg")));
+    assert!(!policy.allows(Some("embed"), "src", Some("/upload.svg")));
+    assert!(!policy.allows(Some("animate"), "attributeName", Some("href")));
+    assert!(!policy.allows(Some("set"), "attributeName", Some("href")));
+
+    assert!(IMAGE_POLICY.allows(Some("a"), "href", Some("https://example.com")));
+    assert!(!IMAGE_POLICY.allows(Some("a"), "href", Some("http://example.com")));
+    assert!(IMAGE_POLICY.allows(Some("IMG"), "aria-label", Some("Logo")));
+    assert!(!IMAGE_POLICY.allows(Some("img"), "width", Some("32")));
+}
+
+#[test]
+fn test_attributes() {
+    let mut attributes = attributes! {
+        href => "javascript:alert(1)",
+        onmouseover => "alert(1)",
+        width => 32,
+        title => "Logo",
+    };
+
+    let mut image = attributes.clone();
+    image.sanitize_for("img", &IMAGE_POLICY);
+    assert_eq!(Attributes::from([("title", "Logo")]), image);
+
+    attributes.sanitize(&Policy::DEFAULT);
+    assert_eq!(
+        Attributes::from([("width", "32"), ("title", "Logo")]),
+        attributes
+    );
+}
+
+#[test]
+fn test_default_policy() {
+    let mut meta = attributes! {
+        "http-equiv" => "refresh",
+        content => "0;url=javascript:alert(1)",
+    };
+    meta.sanitize_for("meta", &Policy::DEFAULT);
+    assert_eq!(
+        Attributes::from([("content", "0;url=javascript:alert(1)")]),
+        meta
+    );
+
+    let mut animate = attributes! {
+        attributeName => "href",
+        values => "javascript:alert(1)",
+        dur => "1s",
+    };
+    animate.sanitize_for("animate", &Policy::DEFAULT);
+    assert!(!animate.contains("attributeName"));
+
+    let mut set = attributes! { attributeName => "href", to => "javascript:alert(1)" };
+    set.sanitize_for("set", &Policy::DEFAULT);
+    assert!(!set.contains("attributeName"));
+
+    let mut object = attributes! { data => "/upload.svg", r#type => "image/svg+xml" };
+    object.sanitize_for("object", &Policy::DEFAULT);
+    assert_eq!(Attributes::from([("type", "image/svg+xml")]), object);
+
+    let mut embed = attributes! { src => "/upload.svg", width => 32 };
+    embed.sanitize_for("embed", &Policy::DEFAULT);
+    assert_eq!(Attributes::from([("width", "32")]), embed);
+
+    let mut base = attributes! { href => "https://example.com/", target => "_blank" };
+    base.sanitize_for("base", &Policy::DEFAULT);
+    assert_eq!(Attributes::default(), base);
+
+    let mut link = attributes! { rel => "stylesheet", href => "javascript:alert(1)" };
+    link.sanitize_for("link", &Policy::DEFAULT);
+    assert_eq!(Attributes::from([("rel", "stylesheet")]), link);
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "serde")]
+
+use serde_json::json;
+use yew::AttrValue;
+use yew_struct_component::{Attributes, attributes};
+
+#[test]
+fn test_serialize() {
+    assert_eq!(
+        r#"{"aria-label":"Close","hidden":"","title":null,"type":"button"}"#,
+        serde_json::to_string(&attributes! {
+            r#type => "button",
+            title => None::<AttrValue>,
+            hidden,
+            "aria-label" => "Close",
+        })
+        .unwrap()
+    );
+    assert_eq!(
+        json!({}),
+        serde_json::to_value(Attributes::default()).unwrap()
+    );
+}
+
+#[test]
+fn test_deserialize() {
+    assert_eq!(
+        attributes! {
+            "aria-label" => "Close",
+            disabled,
+            tabindex => -1,
+            title => None::<AttrValue>,
+            hidden => false,
+        },
+        serde_json::from_value::<Attributes>(json!({
+            "Aria-Label": "Close",
+            "disabled": true,
+            "tabindex": -1,
+            "title": null,
+            "hidden": false,
+        }))
+        .unwrap()
+    );
+    assert_eq!(
+        Attributes::default(),
+        serde_json::from_value::<Attributes>(json!(null)).unwrap()
+    );
+    assert!(serde_json::from_value::<Attributes>(json!({ "title": ["a"] })).is_err());
+}
+
+#[test]
+fn test_round_trip() {
+    let attributes = attributes! {
+        "data-state" => "open",
+        class => "button",
+        title => None::<AttrValue>,
+        viewBox => "0 0 24 24",
+    };
+    let json = serde_json::to_string(&attributes).unwrap();
+
+    assert_eq!(
+        r#"{"class":"button","data-state":"open","title":null,"viewBox":"0 0 24 24"}"#,
+        json
+    );
+    assert_eq!(
+        attributes,
+        serde_json::from_str::<Attributes>(&json).unwrap()
+    );
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "tracing")]
+
+use std::{
+    fmt::Debug,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use tracing::{
+    Event, Metadata, Subscriber,
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    subscriber::with_default,
+};
+use yew::prelude::*;
+use yew_struct_component::StructComponent;
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "button")]
+struct ButtonChildProps {
+    pub r#type: AttrValue,
+    pub disabled: bool,
+    pub title: Option<AttrValue>,
+
+    pub onclick: Callback<MouseEvent>,
+}
+
+#[derive(Default)]
+struct Fields(Vec<String>);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.push(format!("{}={:?}", field.name(), value));
+    }
+}
+
+#[derive(Default)]
+struct Recorder {
+    next_id: AtomicU64,
+    records: Arc<Mutex<Vec<String>>>,
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = Fields::default();
+        span.record(&mut fields);
+        self.records
+            .lock()
+            .expect("Lock should not be poisoned.")
+            .push(format!("{} {}", span.metadata().name(), fields.0.join(" ")));
+
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        self.records
+            .lock()
+            .expect("Lock should not be poisoned.")
+            .push(format!(
+                "{} {}",
+                event.metadata().level(),
+                fields.0.join(" ")
+            ));
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[test]
+fn test() {
+    let recorder = Recorder::default();
+    let records = recorder.records.clone();
+
+    let _ = with_default(recorder, || {
+        ButtonChildProps {
+            r#type: AttrValue::Static("button"),
+            disabled: true,
+            title: None,
+
+            onclick: Callback::noop(),
+        }
+        .render(html! { "Button" })
+    });
+
+    let records = records.lock().expect("Lock should not be poisoned.");
+
+    assert_eq!(
+        vec![
+            r#"render component="ButtonChildProps""#,
+            r#"DEBUG message=rendered tag="button" attributes=2 listeners=1"#,
+            r#"TRACE message=resolved attributes attributes=[("type", "button"), ("disabled", "")]"#,
+        ],
+        *records
+    );
+}
@@ -0,0 +1,91 @@
+use yew::{ServerRenderer, prelude::*, virtual_dom::AttributeOrProperty};
+use yew_struct_component::{Attributes, StructComponent};
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "button")]
+struct ButtonChildProps {
+    pub id: Option<String>,
+    pub r#type: Option<String>,
+}
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "hr", no_children = true)]
+struct SeparatorChildProps {
+    pub role: Option<String>,
+}
+
+#[function_component]
+fn App() -> Html {
+    let mut button = ButtonChildProps {
+        id: Some("button".to_owned()),
+        r#type: None,
+    }
+    .into_vtag(html! { "Click me" });
+    button.add_attribute("type", "submit");
+
+    html! {
+        <>
+            {button}
+            {SeparatorChildProps {
+                role: Some("separator".to_owned()),
+            }}
+        </>
+    }
+}
+
+#[tokio::test]
+async fn test() {
+    let renderer = ServerRenderer::<App>::new().hydratable(false);
+    let html = renderer.render().await;
+
+    assert_eq!(
+        r#"<button id="button" type="submit">Click me</button><hr role="separator">"#,
+        html
+    );
+}
+
+#[derive(Clone, PartialEq, StructComponent)]
+#[struct_component(tag = "span")]
+struct LabelChildProps {
+    pub attributes: Attributes,
+
+    pub id: Option<String>,
+}
+
+#[test]
+fn test_attributes() {
+    let button = ButtonChildProps {
+        id: Some("button".to_owned()),
+        r#type: None,
+    }
+    .into_vtag(Html::default());
+
+    match &button.attributes {
+        yew::virtual_dom::Attributes::Dynamic { keys, values } => {
+            assert_eq!(&["id", "type"], keys);
+            assert_eq!(
+                [
+                    Some(AttributeOrProperty::Attribute(AttrValue::from("button"))),
+                    None
+                ],
+                **values
+            );
+        }
+        _ => panic!("Attributes should be dynamic."),
+    }
+
+    let label = LabelChildProps {
+        attributes: Attributes::from([("title", "Label")]),
+        id: Some("label".to_owned()),
+    }
+    .into_vtag(Html::default());
+
+    assert!(matches!(
+        label.attributes,
+        yew::virtual_dom::Attributes::IndexMap(_)
+    ));
+    assert_eq!(
+        vec![("id", "label"), ("title", "Label")],
+        label.attributes.iter().collect::<Vec<_>>()
+    );
+}
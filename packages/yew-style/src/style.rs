@@ -1,6 +1,7 @@
 use std::{
     fmt::{self, Display},
     ops::Deref,
+    rc::Rc,
 };
 
 use indexmap::IndexMap;
@@ -17,28 +18,75 @@ fn style_map_to_string(map: &IndexMap<String, Option<String>>) -> String {
         .join(" ")
 }
 
+/// Split a style string into declarations, ignoring semicolons in strings and functions like `url()`.
+fn parse_declarations(string: &str) -> IndexMap<String, Option<String>> {
+    let mut declarations = vec![];
+    let mut start = 0;
+    let mut depth = 0usize;
+    let mut quote = None;
+
+    for (index, character) in string.char_indices() {
+        match (quote, character) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(character),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, ';') if depth == 0 => {
+                declarations.push(&string[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    declarations.push(&string[start..]);
+
+    declarations
+        .into_iter()
+        .filter_map(|declaration| {
+            let (key, value) = declaration.split_once(':')?;
+            let key = key.trim();
+
+            (!key.is_empty()).then(|| (key.to_string(), Some(value.trim().to_string())))
+        })
+        .collect()
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum InnerStyle {
-    String(String),
-    Structured(IndexMap<String, Option<String>>),
+    String(AttrValue),
+    Structured(Rc<IndexMap<String, Option<String>>>),
 }
 
 impl InnerStyle {
+    /// Parse a string style into declarations, so merging replaces declarations instead of appending them.
+    pub fn into_structured(self) -> Self {
+        match self {
+            Self::String(string) => Self::Structured(Rc::new(parse_declarations(&string))),
+            Self::Structured(map) => Self::Structured(map),
+        }
+    }
+
     pub fn with_defaults<I: Into<InnerStyle>>(self, defaults: I) -> Self {
         let defaults: InnerStyle = defaults.into();
 
         match (self, defaults) {
             (Self::String(string), Self::String(default_string)) => {
-                Self::String(format!("{default_string} {string}"))
-            }
-            (Self::String(string), Self::Structured(default_map)) => {
-                Self::String(format!("{} {}", style_map_to_string(&default_map), string))
-            }
-            (Self::Structured(map), Self::String(default_string)) => {
-                Self::String(format!("{} {}", default_string, style_map_to_string(&map)))
+                Self::String(AttrValue::from(format!("{default_string} {string}")))
             }
+            (Self::String(string), Self::Structured(default_map)) => Self::String(AttrValue::from(
+                format!("{} {}", style_map_to_string(&default_map), string),
+            )),
+            (Self::Structured(map), Self::String(default_string)) => Self::String(AttrValue::from(
+                format!("{} {}", default_string, style_map_to_string(&map)),
+            )),
             (Self::Structured(map), Self::Structured(default_map)) => {
-                InnerStyle::Structured(default_map.into_iter().chain(map).collect())
+                InnerStyle::Structured(Rc::new(
+                    Rc::unwrap_or_clone(default_map)
+                        .into_iter()
+                        .chain(Rc::unwrap_or_clone(map))
+                        .collect(),
+                ))
             }
         }
     }
@@ -61,6 +109,10 @@ impl Style {
         Self::default()
     }
 
+    pub fn into_structured(self) -> Self {
+        Style(self.0.map(InnerStyle::into_structured))
+    }
+
     pub fn with_defaults<I: Into<Self>>(self, defaults: I) -> Self {
         let defaults: Self = defaults.into();
 
@@ -96,88 +148,90 @@ impl Display for Style {
 
 impl From<Option<&str>> for Style {
     fn from(value: Option<&str>) -> Style {
-        Style(value.map(|value| InnerStyle::String(value.to_string())))
+        Style(value.map(|value| InnerStyle::String(AttrValue::from(value.to_string()))))
     }
 }
 
 impl From<Option<String>> for Style {
     fn from(value: Option<String>) -> Style {
-        Style(value.map(InnerStyle::String))
+        Style(value.map(|value| InnerStyle::String(AttrValue::from(value))))
     }
 }
 
 impl From<&str> for Style {
     fn from(value: &str) -> Style {
-        Style(Some(InnerStyle::String(value.to_string())))
+        Style(Some(InnerStyle::String(AttrValue::from(value.to_string()))))
     }
 }
 
 impl From<String> for Style {
     fn from(value: String) -> Style {
-        Style(Some(InnerStyle::String(value)))
+        Style(Some(InnerStyle::String(AttrValue::from(value))))
     }
 }
 
 impl From<IndexMap<String, Option<String>>> for Style {
     fn from(value: IndexMap<String, Option<String>>) -> Style {
-        Style(Some(InnerStyle::Structured(value)))
+        Style(Some(InnerStyle::Structured(Rc::new(value))))
     }
 }
 
 impl From<IndexMap<String, String>> for Style {
     fn from(value: IndexMap<String, String>) -> Style {
-        Style(Some(InnerStyle::Structured(
+        Style(Some(InnerStyle::Structured(Rc::new(
             value
                 .into_iter()
                 .map(|(key, value)| (key, Some(value)))
                 .collect(),
-        )))
+        ))))
     }
 }
 
 impl<const N: usize> From<[(&str, Option<&str>); N]> for Style {
     fn from(value: [(&str, Option<&str>); N]) -> Style {
-        Style(Some(InnerStyle::Structured(IndexMap::from_iter(
+        Style(Some(InnerStyle::Structured(Rc::new(IndexMap::from_iter(
             value.map(|(key, value)| (key.to_string(), value.map(|value| value.to_string()))),
-        ))))
+        )))))
     }
 }
 
 impl<const N: usize> From<[(&str, &str); N]> for Style {
     fn from(value: [(&str, &str); N]) -> Style {
-        Style(Some(InnerStyle::Structured(IndexMap::from_iter(
+        Style(Some(InnerStyle::Structured(Rc::new(IndexMap::from_iter(
             value.map(|(key, value)| (key.to_string(), Some(value.to_string()))),
-        ))))
+        )))))
     }
 }
 
 impl<const N: usize> From<[(&str, Option<String>); N]> for Style {
     fn from(value: [(&str, Option<String>); N]) -> Style {
-        Style(Some(InnerStyle::Structured(IndexMap::from_iter(
+        Style(Some(InnerStyle::Structured(Rc::new(IndexMap::from_iter(
             value.map(|(key, value)| (key.to_string(), value)),
-        ))))
+        )))))
     }
 }
 
 impl<const N: usize> From<[(&str, String); N]> for Style {
     fn from(value: [(&str, String); N]) -> Style {
-        Style(Some(InnerStyle::Structured(IndexMap::from_iter(
+        Style(Some(InnerStyle::Structured(Rc::new(IndexMap::from_iter(
             value.map(|(key, value)| (key.to_string(), Some(value))),
-        ))))
+        )))))
     }
 }
 
 impl<const N: usize> From<[(String, Option<String>); N]> for Style {
     fn from(value: [(String, Option<String>); N]) -> Style {
-        Style(Some(InnerStyle::Structured(IndexMap::from_iter(value))))
+        Style(Some(InnerStyle::Structured(Rc::new(IndexMap::from_iter(
+            value,
+        )))))
     }
 }
 
 impl<const N: usize> From<[(String, String); N]> for Style {
     fn from(value: [(String, String); N]) -> Style {
-        Style(Some(InnerStyle::Structured(IndexMap::from_iter(
+        Style(Some(InnerStyle::Structured(Rc::new(IndexMap::from_iter(
             value.map(|(key, value)| (key, Some(value))),
-        ))))
+        )))))
     }
 }
 
@@ -255,25 +309,31 @@ impl<const N: usize> IntoPropValue<Style> for [(String, String); N] {
 
 impl From<&InnerStyle> for AttrValue {
     fn from(value: &InnerStyle) -> Self {
-        AttrValue::from(value.to_string())
+        match value {
+            InnerStyle::String(string) => string.clone(),
+            InnerStyle::Structured(_) => AttrValue::from(value.to_string()),
+        }
     }
 }
 
 impl From<&Style> for AttrValue {
     fn from(value: &Style) -> Self {
-        AttrValue::from(value.to_string())
+        value
+            .as_ref()
+            .map(AttrValue::from)
+            .unwrap_or(AttrValue::Static(""))
     }
 }
 
 impl From<InnerStyle> for AttrValue {
     fn from(value: InnerStyle) -> Self {
-        AttrValue::from(value.to_string())
+        AttrValue::from(&value)
     }
 }
 
 impl From<Style> for AttrValue {
     fn from(value: Style) -> Self {
-        AttrValue::from(value.to_string())
+        AttrValue::from(&value)
     }
 }
 
@@ -289,6 +349,76 @@ impl IntoPropValue<AttrValue> for Style {
     }
 }
 
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use std::fmt;
+
+    use indexmap::IndexMap;
+    use serde::{
+        Deserialize, Deserializer, Serialize, Serializer,
+        de::{MapAccess, Visitor},
+    };
+
+    use super::{InnerStyle, Style};
+
+    /// Serialized as a CSS string or a declaration map, matching how the style was created.
+    impl Serialize for Style {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match &self.0 {
+                None => serializer.serialize_none(),
+                Some(InnerStyle::String(string)) => serializer.serialize_str(string),
+                Some(InnerStyle::Structured(map)) => serializer.collect_map(map.iter()),
+            }
+        }
+    }
+
+    /// Deserialized from a CSS string, a declaration map or `null`.
+    impl<'de> Deserialize<'de> for Style {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(StyleVisitor)
+        }
+    }
+
+    struct StyleVisitor;
+
+    impl<'de> Visitor<'de> for StyleVisitor {
+        type Value = Style;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a CSS string or a map of declarations")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Style, E> {
+            Ok(Style::from(value))
+        }
+
+        fn visit_string<E>(self, value: String) -> Result<Style, E> {
+            Ok(Style::from(value))
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Style, A::Error> {
+            let mut declarations = IndexMap::new();
+            while let Some((key, value)) = map.next_entry::<String, Option<String>>()? {
+                declarations.insert(key, value);
+            }
+
+            Ok(Style::from(declarations))
+        }
+
+        fn visit_none<E>(self) -> Result<Style, E> {
+            Ok(Style::default())
+        }
+
+        fn visit_unit<E>(self) -> Result<Style, E> {
+            Ok(Style::default())
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Style, D::Error> {
+            deserializer.deserialize_any(StyleVisitor)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -330,6 +460,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_clone() {
+        let style = Style::from([("color", "red")]);
+        let clone = style.clone();
+
+        match (&*style, &*clone) {
+            (Some(InnerStyle::Structured(map)), Some(InnerStyle::Structured(clone_map))) => {
+                assert!(Rc::ptr_eq(map, clone_map));
+            }
+            _ => unreachable!("Style should be structured."),
+        }
+    }
+
+    #[test]
+    fn test_into_structured() {
+        assert_eq!(
+            Style::from([
+                ("color", "red"),
+                ("background", "url(\"a;b.png\")"),
+                ("--gap", "1rem"),
+            ]),
+            Style::from("color: red; background: url(\"a;b.png\"); ; --gap:1rem").into_structured(),
+        );
+        assert_eq!(
+            "color: blue; padding: 0; margin: 0;",
+            Style::from("color: blue; margin: 0;")
+                .into_structured()
+                .with_defaults(Style::from("color: red; padding: 0").into_structured())
+                .to_string(),
+        );
+    }
+
     #[test]
     fn test_with_defaults() {
         // String with string defaults
@@ -0,0 +1,56 @@
+#![cfg(feature = "serde")]
+
+use serde_json::json;
+use yew_style::Style;
+
+#[test]
+fn test_serialize() {
+    assert_eq!(json!(null), serde_json::to_value(Style::default()).unwrap());
+    assert_eq!(
+        json!("color: red;"),
+        serde_json::to_value(Style::from("color: red;")).unwrap()
+    );
+    assert_eq!(
+        r#"{"color":"red","background-color":null,"border":"1px solid black"}"#,
+        serde_json::to_string(&Style::from([
+            ("color", Some("red")),
+            ("background-color", None),
+            ("border", Some("1px solid black")),
+        ]))
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_deserialize() {
+    assert_eq!(
+        Style::default(),
+        serde_json::from_value::<Style>(json!(null)).unwrap()
+    );
+    assert_eq!(
+        Style::from("color: red;"),
+        serde_json::from_value::<Style>(json!("color: red;")).unwrap()
+    );
+    assert_eq!(
+        Style::from([("color", Some("red")), ("background-color", None)]),
+        serde_json::from_str::<Style>(r#"{"color":"red","background-color":null}"#).unwrap()
+    );
+    assert!(serde_json::from_value::<Style>(json!(1)).is_err());
+}
+
+#[test]
+fn test_round_trip() {
+    for style in [
+        Style::default(),
+        Style::from("margin: 1rem; padding: 0.5rem;"),
+        Style::from([("z-index", "1"), ("color", "red"), ("margin", "0")]),
+    ] {
+        let json = serde_json::to_string(&style).unwrap();
+
+        assert_eq!(style, serde_json::from_str::<Style>(&json).unwrap());
+        assert_eq!(
+            json,
+            serde_json::to_string(&serde_json::from_str::<Style>(&json).unwrap()).unwrap()
+        );
+    }
+}